@@ -0,0 +1,102 @@
+//! Compares `StrExt::levenshtein_distance` against the plain dynamic programming
+//! implementation it used to be based on.
+//!
+//! Run with `cargo bench --bench levenshtein`.
+
+use std::time::{Duration, Instant};
+
+use string_more::StrExt;
+
+// The scalar implementation adapted from rosettacode,
+// kept here as the baseline for the comparison.
+fn scalar_levenshtein_distance(source: &str, target: &str) -> usize {
+    let target_len = target.chars().count();
+    let mut costs = (0..=target_len).collect::<Vec<_>>();
+
+    for (source_index, source_char) in source.chars().enumerate() {
+        let mut corner = source_index;
+        costs[0] = source_index + 1;
+
+        for (target_index, target_char) in target.chars().enumerate() {
+            let upper = costs[target_index + 1];
+
+            costs[target_index + 1] = if source_char == target_char {
+                corner
+            } else {
+                1 + usize::min(usize::min(costs[target_index], upper), corner)
+            };
+
+            corner = upper;
+        }
+    }
+
+    costs[target_len]
+}
+
+fn words(count: usize, min_len: usize, max_len: usize) -> Vec<String> {
+    const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz_";
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as usize
+    };
+
+    (0..count)
+        .map(|_| {
+            let len = min_len + next() % (max_len - min_len + 1);
+            (0..len)
+                .map(|_| ALPHABET[next() % ALPHABET.len()] as char)
+                .collect()
+        })
+        .collect()
+}
+
+fn measure(candidates: &[String], queries: &[String], f: impl Fn(&str, &str) -> usize) -> Duration {
+    let start = Instant::now();
+    let mut checksum = 0;
+
+    for query in queries {
+        for candidate in candidates {
+            checksum += f(query, candidate);
+        }
+    }
+
+    let elapsed = start.elapsed();
+    // printing the checksum keeps the optimizer from discarding the computations.
+    println!("    checksum: {checksum}");
+    elapsed
+}
+
+fn bench(name: &str, candidates: &[String], queries: &[String]) {
+    println!("{name} ({} x {} pairs)", queries.len(), candidates.len());
+
+    let scalar = measure(candidates, queries, scalar_levenshtein_distance);
+    let current = measure(candidates, queries, |a, b| a.levenshtein_distance(b));
+
+    println!("    scalar:       {scalar:?}");
+    println!("    bit-parallel: {current:?}");
+    println!(
+        "    speedup:      {:.2}x",
+        scalar.as_secs_f64() / current.as_secs_f64()
+    );
+}
+
+fn main() {
+    bench(
+        "identifiers (4..=16 chars)",
+        &words(5000, 4, 16),
+        &words(20, 4, 16),
+    );
+    bench(
+        "sentences (32..=64 chars)",
+        &words(1000, 32, 64),
+        &words(20, 32, 64),
+    );
+    bench(
+        "paragraphs (200..=400 chars)",
+        &words(100, 200, 400),
+        &words(10, 200, 400),
+    );
+}
@@ -0,0 +1,295 @@
+//! Pairwise alignment of strings with affine gap scores, using the three matrices formulation
+//! by O. Gotoh in "An improved algorithm for matching biological sequences" (1982).
+//!
+//! Global alignments follow S. B. Needleman and C. D. Wunsch, "A general method applicable
+//! to the search for similarities in the amino acid sequence of two proteins" (1970):
+//! the alignment spans both strings entirely.
+//!
+//! Local alignments follow T. F. Smith and M. S. Waterman, "Identification of common
+//! molecular subsequences" (1981): scores never drop below zero, so that the alignment
+//! may start and end anywhere in both strings.
+
+use std::ops::Range;
+
+use crate::EditOperation;
+
+/// The scores rewarding or penalizing each step of an alignment
+/// (see `StrExt::smith_waterman` and `StrExt::needleman_wunsch`).
+///
+/// Aligned chars score `match_score` when equal and `mismatch_score` otherwise,
+/// while a gap of `n` chars in either string scores `gap_open + (n - 1) * gap_extend`.
+/// Rewards are positive and penalties are negative.
+///
+/// The default scores are `2.0` for matches, `-1.0` for mismatches, `-2.0` for opening
+/// a gap and `-1.0` for extending it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlignmentScores {
+    pub match_score: f64,
+    pub mismatch_score: f64,
+    pub gap_open: f64,
+    pub gap_extend: f64,
+}
+
+impl Default for AlignmentScores {
+    fn default() -> Self {
+        Self {
+            match_score: 2.0,
+            mismatch_score: -1.0,
+            gap_open: -2.0,
+            gap_extend: -1.0,
+        }
+    }
+}
+
+/// An alignment between a source string and a target string.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{AlignmentScores, EditOperation, StrExt};
+///
+/// let alignment = "the quick brown fox".smith_waterman("quack", &AlignmentScores::default());
+/// assert_eq!(alignment.score, 7.0);
+/// assert_eq!(alignment.source, 4..9);
+/// assert_eq!(alignment.target, 0..5);
+/// assert_eq!(alignment.operations.iter().filter(|o| o.is_edit()).count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alignment {
+    /// The total score of the alignment.
+    pub score: f64,
+    /// The byte range of the aligned region in the source string.
+    pub source: Range<usize>,
+    /// The byte range of the aligned region in the target string.
+    pub target: Range<usize>,
+    /// The operations turning the aligned region of the source into
+    /// the aligned region of the target, with byte ranges in the whole strings.
+    pub operations: Vec<EditOperation>,
+}
+
+/// Whether an alignment spans the strings entirely or only a region of each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Global,
+    Local,
+}
+
+/// How the best score of a cell has been reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    /// The alignment starts at the cell.
+    Start,
+    /// Aligning the chars of both strings.
+    Diagonal,
+    /// Skipping a char of the source string, a gap in the target.
+    Up,
+    /// Skipping a char of the target string, a gap in the source.
+    Left,
+}
+
+/// The chars of `s` paired with their byte ranges.
+fn char_ranges(s: &str) -> Vec<(char, Range<usize>)> {
+    s.char_indices()
+        .map(|(i, c)| (c, i..i + c.len_utf8()))
+        .collect()
+}
+
+/// Returns the byte index following the first `n` chars of `ranges`.
+fn position(ranges: &[(char, Range<usize>)], n: usize) -> usize {
+    n.checked_sub(1).map_or(0, |i| ranges[i].1.end)
+}
+
+/// Computes the best global alignment between `source` and `target`.
+pub(crate) fn needleman_wunsch(source: &str, target: &str, scores: &AlignmentScores) -> Alignment {
+    align(source, target, scores, Mode::Global)
+}
+
+/// Computes the best local alignment between `source` and `target`.
+pub(crate) fn smith_waterman(source: &str, target: &str, scores: &AlignmentScores) -> Alignment {
+    align(source, target, scores, Mode::Local)
+}
+
+fn align(source: &str, target: &str, scores: &AlignmentScores, mode: Mode) -> Alignment {
+    let (source, target) = (char_ranges(source), char_ranges(target));
+    let columns = target.len() + 1;
+    let cells = (source.len() + 1) * columns;
+
+    // the best scores of the alignments ending at each cell, overall and ending with
+    // a gap in the target (up) or in the source (left), along with how they are reached.
+    let mut best = vec![0.0; cells];
+    let mut up = vec![f64::NEG_INFINITY; cells];
+    let mut left = vec![f64::NEG_INFINITY; cells];
+    let mut steps = vec![Step::Start; cells];
+    let mut up_extends = vec![false; cells];
+    let mut left_extends = vec![false; cells];
+    let mut end = 0;
+
+    if mode == Mode::Global {
+        // global alignments may only start at the origin, skipping the leading chars
+        // of either string with a single gap.
+        for i in 1..=source.len() {
+            let cell = i * columns;
+            up[cell] = scores.gap_open + (i - 1) as f64 * scores.gap_extend;
+            up_extends[cell] = i > 1;
+            best[cell] = up[cell];
+            steps[cell] = Step::Up;
+        }
+
+        for j in 1..columns {
+            left[j] = scores.gap_open + (j - 1) as f64 * scores.gap_extend;
+            left_extends[j] = j > 1;
+            best[j] = left[j];
+            steps[j] = Step::Left;
+        }
+    }
+
+    for i in 1..=source.len() {
+        for j in 1..columns {
+            let cell = i * columns + j;
+
+            let (open, extend) = (
+                best[cell - columns] + scores.gap_open,
+                up[cell - columns] + scores.gap_extend,
+            );
+            up[cell] = f64::max(open, extend);
+            up_extends[cell] = extend > open;
+
+            let (open, extend) = (
+                best[cell - 1] + scores.gap_open,
+                left[cell - 1] + scores.gap_extend,
+            );
+            left[cell] = f64::max(open, extend);
+            left_extends[cell] = extend > open;
+
+            let diagonal = best[cell - columns - 1]
+                + if source[i - 1].0 == target[j - 1].0 {
+                    scores.match_score
+                } else {
+                    scores.mismatch_score
+                };
+
+            // on ties, aligning chars is favoured over skipping
+            // source chars, which is favoured over skipping target chars.
+            let (mut score, mut step) = (diagonal, Step::Diagonal);
+            for (gap, gap_step) in [(up[cell], Step::Up), (left[cell], Step::Left)] {
+                if gap > score {
+                    score = gap;
+                    step = gap_step;
+                }
+            }
+
+            if mode == Mode::Global || score > 0.0 {
+                best[cell] = score;
+                steps[cell] = step;
+            }
+
+            if mode == Mode::Local && best[cell] > best[end] {
+                end = cell;
+            }
+        }
+    }
+
+    if mode == Mode::Global {
+        end = cells - 1;
+    }
+
+    // walk back from the last cell of the alignment up to its start.
+    let mut operations = Vec::new();
+    let (mut i, mut j) = (end / columns, end % columns);
+    let (end_i, end_j) = (i, j);
+    let mut step = steps[end];
+
+    while step != Step::Start {
+        match step {
+            Step::Diagonal => {
+                let (source_char, source_range) = source[i - 1].clone();
+                let (target_char, target_range) = target[j - 1].clone();
+
+                operations.push(if source_char == target_char {
+                    EditOperation::Keep {
+                        source: source_range,
+                        target: target_range,
+                    }
+                } else {
+                    EditOperation::Substitute {
+                        source: source_range,
+                        target: target_range,
+                    }
+                });
+
+                i -= 1;
+                j -= 1;
+                step = steps[i * columns + j];
+            }
+            Step::Up => {
+                let at = position(&target, j);
+                operations.push(EditOperation::Delete {
+                    source: source[i - 1].1.clone(),
+                    target: at..at,
+                });
+
+                let extends = up_extends[i * columns + j];
+                i -= 1;
+                step = if extends {
+                    Step::Up
+                } else {
+                    steps[i * columns + j]
+                };
+            }
+            Step::Left => {
+                let at = position(&source, i);
+                operations.push(EditOperation::Insert {
+                    source: at..at,
+                    target: target[j - 1].1.clone(),
+                });
+
+                let extends = left_extends[i * columns + j];
+                j -= 1;
+                step = if extends {
+                    Step::Left
+                } else {
+                    steps[i * columns + j]
+                };
+            }
+            Step::Start => unreachable!(),
+        }
+    }
+
+    operations.reverse();
+
+    Alignment {
+        score: best[end],
+        source: position(&source, i)..position(&source, end_i),
+        target: position(&target, j)..position(&target, end_j),
+        operations,
+    }
+}
+
+/// Renders the strings aligned by `operations`, writing `gap` in place of each char
+/// that is missing from either string.
+pub(crate) fn render(
+    source: &str,
+    target: &str,
+    operations: &[EditOperation],
+    gap: &str,
+) -> (String, String) {
+    let mut aligned = (
+        String::with_capacity(source.len() + gap.len() * operations.len()),
+        String::with_capacity(target.len() + gap.len() * operations.len()),
+    );
+
+    for operation in operations {
+        let (source_range, target_range) = (operation.source(), operation.target());
+        aligned.0.push_str(if source_range.is_empty() {
+            gap
+        } else {
+            &source[source_range]
+        });
+        aligned.1.push_str(if target_range.is_empty() {
+            gap
+        } else {
+            &target[target_range]
+        });
+    }
+
+    aligned
+}
@@ -0,0 +1,157 @@
+//! Approximate string matching, finding the substrings of a text within a bounded Levenshtein
+//! distance from a pattern by Sellers' algorithm, bit-parallel for patterns of up to 64 chars.
+//!
+//! The text is read one char at a time, computing the least distance between the pattern and
+//! the substrings ending at each char. Since the substrings ending at consecutive chars overlap,
+//! only the ends where the distance reaches a local minimum are reported, and the start of
+//! each occurrence is then found by aligning the pattern backwards from its end.
+
+use std::ops::Range;
+use std::str::CharIndices;
+
+use crate::myers;
+
+/// The least distances between the pattern and the substrings ending at each char of the text.
+enum Columns {
+    /// The bit-vectors of the last column of the matrix, for patterns of up to 64 chars.
+    Bits(Box<myers::Search<char>>),
+    /// The last column of the matrix, for longer patterns.
+    Costs(Vec<usize>),
+}
+
+impl Columns {
+    fn step(&mut self, pattern: &[char], c: char) -> usize {
+        match self {
+            Self::Bits(search) => search.step(c),
+            Self::Costs(costs) => {
+                // the first row is all zeros, so that substrings may start anywhere.
+                let mut corner = costs[0];
+
+                for (i, &p) in pattern.iter().enumerate() {
+                    let upper = costs[i + 1];
+                    costs[i + 1] = if p == c {
+                        corner
+                    } else {
+                        1 + usize::min(usize::min(costs[i], upper), corner)
+                    };
+                    corner = upper;
+                }
+
+                costs[pattern.len()]
+            }
+        }
+    }
+}
+
+/// An iterator over the approximate occurrences of a pattern in a text, along with their
+/// byte range and Levenshtein distance from the pattern, as computed by `StrExt::find_approx`.
+pub struct ApproxMatches<'a> {
+    text: &'a str,
+    pattern: Vec<char>,
+    max: usize,
+    chars: CharIndices<'a>,
+    columns: Columns,
+    /// The distance at the previous end, if it did not exceed `max`.
+    previous: Option<usize>,
+    /// The end of the best occurrence found so far in the current run, and its distance.
+    candidate: Option<(usize, usize)>,
+}
+
+impl<'a> ApproxMatches<'a> {
+    pub(crate) fn new(text: &'a str, pattern: &str, max: usize) -> Self {
+        let pattern = pattern.chars().collect::<Vec<_>>();
+        let columns = if pattern.len() <= u64::BITS as usize && !pattern.is_empty() {
+            Columns::Bits(Box::new(myers::Search::new(
+                pattern.iter().copied(),
+                pattern.len(),
+            )))
+        } else {
+            Columns::Costs((0..=pattern.len()).collect())
+        };
+
+        // an empty pattern has no occurrences.
+        let chars = if pattern.is_empty() {
+            text[text.len()..].char_indices()
+        } else {
+            text.char_indices()
+        };
+
+        Self {
+            text,
+            // the empty substring is within `pattern.len()` edits of the pattern.
+            max: max.min(pattern.len().saturating_sub(1)),
+            pattern,
+            chars,
+            columns,
+            previous: None,
+            candidate: None,
+        }
+    }
+
+    /// Returns the occurrence ending at `end` with `distance` edits, starting as late as possible.
+    fn occurrence(&self, end: usize, distance: usize) -> (Range<usize>, usize) {
+        // aligns the reversed pattern with the reversed text preceding `end`, which an
+        // occurrence spans for at most `pattern.len() + distance` chars.
+        let mut costs = (0..=self.pattern.len()).collect::<Vec<_>>();
+        let mut start = end;
+
+        for (index, c) in self.text[..end]
+            .char_indices()
+            .rev()
+            .take(self.pattern.len() + distance)
+        {
+            if costs[self.pattern.len()] == distance {
+                break;
+            }
+
+            let mut corner = costs[0];
+            costs[0] += 1;
+
+            for (i, &p) in self.pattern.iter().rev().enumerate() {
+                let upper = costs[i + 1];
+                costs[i + 1] = if p == c {
+                    corner
+                } else {
+                    1 + usize::min(usize::min(costs[i], upper), corner)
+                };
+                corner = upper;
+            }
+
+            start = index;
+        }
+
+        (start..end, distance)
+    }
+}
+
+impl Iterator for ApproxMatches<'_> {
+    type Item = (Range<usize>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((index, c)) = self.chars.next() {
+            let end = index + c.len_utf8();
+            let distance = self.columns.step(&self.pattern, c);
+
+            let previous = self.previous;
+            self.previous = (distance <= self.max).then_some(distance);
+
+            match self.candidate {
+                // the distance rises again, the candidate is a local minimum.
+                Some((candidate_end, candidate)) if distance > candidate => {
+                    self.candidate = None;
+                    return Some(self.occurrence(candidate_end, candidate));
+                }
+                // the distance keeps decreasing, or the occurrence extends at no cost.
+                Some(_) => self.candidate = Some((end, distance)),
+                // a new descent starts.
+                None if distance <= self.max && previous.map_or(true, |p| distance < p) => {
+                    self.candidate = Some((end, distance));
+                }
+                None => {}
+            }
+        }
+
+        let (end, distance) = self.candidate.take()?;
+        Some(self.occurrence(end, distance))
+    }
+}
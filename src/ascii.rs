@@ -0,0 +1,94 @@
+//! Fast paths for ASCII strings, whose chars are their bytes, so that they
+//! can be iterated, counted and indexed without decoding UTF-8.
+
+use std::iter::FusedIterator;
+use std::{slice, str};
+
+/// The high bit of every byte of a word, set only by non-ASCII bytes.
+const HIGH_BITS: u64 = u64::from_ne_bytes([0x80; 8]);
+
+/// Returns `true` if `s` is made up of ASCII chars only, checking 8 bytes at a time.
+pub(crate) fn is_ascii(s: &str) -> bool {
+    let chunks = s.as_bytes().chunks_exact(8);
+    let remainder = chunks.remainder();
+
+    chunks
+        .map(|chunk| u64::from_ne_bytes(chunk.try_into().unwrap()))
+        .fold(0, |acc, word| acc | word)
+        & HIGH_BITS
+        == 0
+        && remainder.is_ascii()
+}
+
+/// Returns the number of chars of `s`, given whether it is made up of ASCII chars only.
+pub(crate) fn char_count(s: &str, ascii: bool) -> usize {
+    if ascii {
+        s.len()
+    } else {
+        s.chars().count()
+    }
+}
+
+/// An iterator over the chars of a string, reading its bytes directly when it is known
+/// to be made up of ASCII chars only.
+#[derive(Debug, Clone)]
+pub(crate) enum Chars<'a> {
+    Ascii(slice::Iter<'a, u8>),
+    Unicode(str::Chars<'a>),
+}
+
+/// Returns an iterator over the chars of `s`, given whether it is made up of ASCII chars only.
+pub(crate) fn chars(s: &str, ascii: bool) -> Chars<'_> {
+    if ascii {
+        Chars::Ascii(s.as_bytes().iter())
+    } else {
+        Chars::Unicode(s.chars())
+    }
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Ascii(bytes) => bytes.next().map(|&b| char::from(b)),
+            Self::Unicode(chars) => chars.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Ascii(bytes) => bytes.size_hint(),
+            Self::Unicode(chars) => chars.size_hint(),
+        }
+    }
+}
+
+impl FusedIterator for Chars<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{char_count, chars, is_ascii};
+
+    #[test]
+    fn ascii() {
+        const SEED: [(&str, bool); 9] = [
+            ("", true),
+            ("a", true),
+            ("abcdefg", true),
+            ("abcdefgh", true),
+            ("abcdefghijklmnopq", true),
+            ("·", false),
+            ("abcdefgh·", false),
+            ("·abcdefgh", false),
+            ("abcd·efghijklmnop", false),
+        ];
+
+        for (sut, expected) in SEED {
+            assert_eq!(is_ascii(sut), expected, "{sut}");
+            assert_eq!(char_count(sut, expected), sut.chars().count(), "{sut}");
+            assert!(chars(sut, expected).eq(sut.chars()), "{sut}");
+        }
+    }
+}
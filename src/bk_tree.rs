@@ -0,0 +1,377 @@
+//! Burkhard-Keller trees, as described by W. A. Burkhard and R. M. Keller in
+//! "Some approaches to best-match file searching" (1973).
+//!
+//! Each node stores a string and the children are keyed by their distance from it,
+//! so that by the triangle inequality a search only needs to visit the children whose
+//! distance from the node differs from the distance of the query by no more than the radius.
+
+use std::cmp::Ordering;
+
+use crate::{Levenshtein, StringMetric};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for usize {}
+impl sealed::Sealed for f64 {}
+impl sealed::Sealed for Option<usize> {}
+
+/// The distances of the metrics that may index a `BkTree`.
+///
+/// This trait is sealed and implemented for `usize` and `f64`, along with `Option<usize>`
+/// where `None` (strings of different lengths for `Hamming`) stands for an infinite distance.
+pub trait BkDistance: Copy + sealed::Sealed {
+    /// Compares two distances, the greater the farther.
+    fn compare(&self, other: &Self) -> Ordering;
+
+    /// Returns `true` if the difference between the distances does not exceed `radius`.
+    fn is_within(self, other: Self, radius: Self) -> bool;
+}
+
+impl BkDistance for usize {
+    fn compare(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+
+    fn is_within(self, other: Self, radius: Self) -> bool {
+        self.max(other) - self.min(other) <= radius
+    }
+}
+
+impl BkDistance for f64 {
+    fn compare(&self, other: &Self) -> Ordering {
+        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+    }
+
+    fn is_within(self, other: Self, radius: Self) -> bool {
+        (self - other).abs() <= radius
+    }
+}
+
+impl BkDistance for Option<usize> {
+    fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    fn is_within(self, other: Self, radius: Self) -> bool {
+        match (self, other, radius) {
+            (_, _, None) | (None, None, _) => true,
+            (Some(a), Some(b), Some(radius)) => a.is_within(b, radius),
+            _ => false,
+        }
+    }
+}
+
+/// A node of the tree, with its children keyed by their distance from it.
+#[derive(Debug, Clone)]
+struct Node<D> {
+    word: String,
+    children: Vec<(D, usize)>,
+}
+
+/// A Burkhard-Keller tree indexing strings by `metric` for nearest-neighbour searches,
+/// which only compute the distance from a fraction of the indexed strings.
+///
+/// Searches are exact as long as the metric satisfies the triangle inequality, as the
+/// edit distances do, while with the other metrics some matches may be missed.
+/// Results are sorted by distance, then by insertion order.
+///
+/// # Examples
+/// ```rust
+/// use string_more::BkTree;
+///
+/// let tree: BkTree = ["book", "books", "cake", "boo", "boon", "cook", "cape", "cart"]
+///     .into_iter()
+///     .collect();
+///
+/// assert_eq!(tree.find_within("bo", 1), [("boo", 1)]);
+/// assert_eq!(tree.find_within("bool", 1), [("book", 1), ("boo", 1), ("boon", 1)]);
+/// assert_eq!(tree.nearest("caqe", 2), [("cake", 1), ("cape", 1)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BkTree<M: StringMetric = Levenshtein> {
+    metric: M,
+    nodes: Vec<Node<M::Distance>>,
+}
+
+impl<M: StringMetric + Default> BkTree<M> {
+    /// Creates an empty tree indexing strings by the default instance of the metric.
+    pub fn new() -> Self {
+        Self::with_metric(M::default())
+    }
+}
+
+impl<M: StringMetric + Default> Default for BkTree<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: StringMetric> BkTree<M> {
+    /// Creates an empty tree indexing strings by `metric`.
+    pub fn with_metric(metric: M) -> Self {
+        Self {
+            metric,
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Returns the number of strings in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the tree contains no strings.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl<M: StringMetric> BkTree<M>
+where
+    M::Distance: BkDistance,
+{
+    /// Inserts `word` in the tree.
+    /// Returns `false` if the tree already contains `word`, leaving the tree unchanged.
+    pub fn insert(&mut self, word: &str) -> bool {
+        let index = self.nodes.len();
+        let node = Node {
+            word: word.to_string(),
+            children: Vec::new(),
+        };
+
+        if self.nodes.is_empty() {
+            self.nodes.push(node);
+            return true;
+        }
+
+        let mut current = 0;
+        loop {
+            if self.nodes[current].word == word {
+                return false;
+            }
+
+            let distance = self.metric.distance(word, &self.nodes[current].word);
+            let child = self.nodes[current]
+                .children
+                .iter()
+                .find(|(d, _)| d.compare(&distance) == Ordering::Equal);
+
+            match child {
+                Some(&(_, child)) => current = child,
+                None => {
+                    self.nodes[current].children.push((distance, index));
+                    self.nodes.push(node);
+                    return true;
+                }
+            }
+        }
+    }
+
+    /// Returns the strings whose distance from `query` does not exceed `k`,
+    /// along with their distance.
+    pub fn find_within(&self, query: &str, k: M::Distance) -> Vec<(&str, M::Distance)> {
+        let mut matches = Vec::new();
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+
+        while let Some(current) = stack.pop() {
+            let node = &self.nodes[current];
+            let distance = self.metric.distance(query, &node.word);
+
+            if distance.compare(&k) != Ordering::Greater {
+                matches.push((current, distance));
+            }
+
+            stack.extend(
+                node.children
+                    .iter()
+                    .filter(|(d, _)| d.is_within(distance, k))
+                    .map(|&(_, child)| child),
+            );
+        }
+
+        self.sorted(matches)
+    }
+
+    /// Returns the `n` strings closest to `query`, along with their distance.
+    pub fn nearest(&self, query: &str, n: usize) -> Vec<(&str, M::Distance)> {
+        // the closest matches found so far, sorted by distance and then by insertion order.
+        let mut matches: Vec<(usize, M::Distance)> =
+            Vec::with_capacity(n.min(self.nodes.len()) + 1);
+        let mut stack = if self.nodes.is_empty() || n == 0 {
+            vec![]
+        } else {
+            vec![0]
+        };
+
+        while let Some(current) = stack.pop() {
+            let node = &self.nodes[current];
+            let distance = self.metric.distance(query, &node.word);
+
+            let position = matches.partition_point(|&(index, d)| {
+                d.compare(&distance).then(index.cmp(&current)) == Ordering::Less
+            });
+            if position < n {
+                matches.insert(position, (current, distance));
+                matches.truncate(n);
+            }
+
+            // once `n` matches have been found the search radius shrinks to the farthest one.
+            let radius = (matches.len() == n).then(|| matches[n - 1].1);
+            stack.extend(
+                node.children
+                    .iter()
+                    .filter(|(d, _)| radius.map_or(true, |radius| d.is_within(distance, radius)))
+                    .map(|&(_, child)| child),
+            );
+        }
+
+        matches
+            .into_iter()
+            .map(|(index, distance)| (self.nodes[index].word.as_str(), distance))
+            .collect()
+    }
+
+    /// Sorts `matches` by distance and then by insertion order, resolving their strings.
+    fn sorted(&self, mut matches: Vec<(usize, M::Distance)>) -> Vec<(&str, M::Distance)> {
+        matches.sort_by(|(a, da), (b, db)| da.compare(db).then(a.cmp(b)));
+        matches
+            .into_iter()
+            .map(|(index, distance)| (self.nodes[index].word.as_str(), distance))
+            .collect()
+    }
+}
+
+impl<S: AsRef<str>, M: StringMetric> Extend<S> for BkTree<M>
+where
+    M::Distance: BkDistance,
+{
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for word in iter {
+            self.insert(word.as_ref());
+        }
+    }
+}
+
+impl<S: AsRef<str>, M: StringMetric + Default> FromIterator<S> for BkTree<M>
+where
+    M::Distance: BkDistance,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BkTree;
+    use crate::{
+        Hamming, JaroWinkler, Levenshtein, StrExt, StringMetric, UniformCosts, WeightedLevenshtein,
+    };
+
+    const WORDS: [&str; 14] = [
+        "book", "books", "cake", "boo", "boon", "cook", "cape", "cart", "", "a", "b", "bo",
+        "kitten", "sitting",
+    ];
+
+    const QUERIES: [&str; 8] = ["", "a", "bo", "bool", "caqe", "kitchen", "zzzz", "books"];
+
+    fn brute_force<M: StringMetric>(
+        metric: &M,
+        query: &str,
+        within: impl Fn(M::Distance) -> bool,
+    ) -> Vec<(&'static str, M::Distance)> {
+        WORDS
+            .iter()
+            .map(|word| (*word, metric.distance(query, word)))
+            .filter(|(_, distance)| within(*distance))
+            .collect()
+    }
+
+    #[test]
+    fn insert() {
+        let mut tree = BkTree::<Levenshtein>::new();
+        assert!(tree.is_empty());
+
+        for (i, word) in WORDS.iter().enumerate() {
+            assert!(tree.insert(word));
+            assert_eq!(tree.len(), i + 1);
+        }
+
+        for word in WORDS {
+            assert!(!tree.insert(word));
+        }
+
+        assert_eq!(tree.len(), WORDS.len());
+    }
+
+    #[test]
+    fn find_within() {
+        let tree: BkTree = WORDS.iter().collect();
+
+        for query in QUERIES {
+            for k in 0..4 {
+                let mut expected = brute_force(&Levenshtein, query, |d| d <= k);
+                expected.sort_by_key(|(_, distance)| *distance);
+                assert_eq!(tree.find_within(query, k), expected, "{query} {k}");
+            }
+        }
+
+        let mut tree = BkTree::with_metric(Hamming);
+        assert!(tree.find_within("book", Some(1)).is_empty());
+
+        tree.extend(WORDS);
+        assert_eq!(
+            tree.find_within("bool", Some(1)),
+            [("book", Some(1)), ("boon", Some(1))]
+        );
+        assert_eq!(tree.find_within("", Some(0)), [("", Some(0))]);
+
+        let mut tree = BkTree::with_metric(WeightedLevenshtein(UniformCosts));
+        tree.extend(WORDS);
+        assert_eq!(tree.find_within("cape", 0.0), [("cape", 0.0)]);
+        assert_eq!(
+            tree.find_within("cape", 1.0),
+            [("cape", 0.0), ("cake", 1.0)]
+        );
+    }
+
+    #[test]
+    fn nearest() {
+        let tree: BkTree = WORDS.iter().collect();
+
+        for query in QUERIES {
+            let mut expected = brute_force(&Levenshtein, query, |_| true);
+            expected.sort_by_key(|(_, distance)| *distance);
+
+            for n in 0..=WORDS.len() + 1 {
+                let expected = &expected[..n.min(expected.len())];
+                assert_eq!(tree.nearest(query, n), expected, "{query} {n}");
+            }
+        }
+
+        assert_eq!(
+            tree.nearest("kitten", 2),
+            [
+                ("kitten", 0),
+                ("sitting", "kitten".levenshtein_distance("sitting"))
+            ]
+        );
+
+        let tree: BkTree<JaroWinkler> = WORDS.iter().collect();
+        assert_eq!(tree.nearest("boks", 1)[0].0, "books");
+        assert!(BkTree::<Levenshtein>::new().nearest("book", 3).is_empty());
+    }
+}
@@ -0,0 +1,299 @@
+//! Normalizations applied to the strings while they are compared, so that differences
+//! in case, accents, whitespace or punctuation can be ignored without allocating
+//! normalized copies of the strings.
+
+use std::char::ToLowercase;
+use std::iter::FusedIterator;
+use std::str;
+
+/// The base letters of the Latin-1 Supplement and Latin Extended-A and -B letters,
+/// from U+00C0 to U+024F, or `_` for the chars that are not accented letters.
+const LATIN: &[u8; 400] = b"\
+    AAAAAA_CEEEEIIII_NOOOOO_OUUUUY__aaaaaa_ceeeeiiii_nooooo_ouuuuy_y\
+    AaAaAaCcCcCcCcDdDdEeEeEeEeEeGgGgGgGgHhHhIiIiIiIiIi__JjKk_LlLlLl_\
+    _LlNnNnNn___OoOoOo__RrRrRrSsSsSsSsTtTtTtUuUuUuUuUuUuWwYyYZzZzZz_\
+    b________D_____________I________Oo_____________Uu_______________\
+    _____________AaIiOoUuUuUuUuUu_AaAa____GgKkOoOo__j___Gg__NnAa____\
+    AaAaEeEeIiIiOoOoRrRrUuUuSsTt__Hh______AaEeOoOoOoOoYy____________\
+    ________________";
+
+/// The base letters of the Latin Extended Additional letters, from U+1E00 to U+1EFF,
+/// or `_` for the chars that are not accented letters.
+const LATIN_EXTENDED_ADDITIONAL: &[u8; 256] = b"\
+    AaBbBbBbCcDdDdDdDdDdEeEeEeEeEeFfGgHhHhHhHhHhIiIiKkKkKkLlLlLlLlMm\
+    MmMmNnNnNnNnOoOoOoOoPpPpRrRrRrRrSsSsSsSsSsTtTtTtTtUuUuUuUuUuVvVv\
+    WwWwWwWwWwXxXxYyZzZzZzhtwy______AaAaAaAaAaAaAaAaAaAaAaAaEeEeEeEe\
+    EeEeEeEeIiIiOoOoOoOoOoOoOoOoOoOoOoOoUuUuUuUuUuUuUuYyYyYyYy______";
+
+/// The options of a comparison between strings, telling which differences the metrics
+/// should ignore. By default strings are compared exactly as they are.
+///
+/// The options are applied to the chars of the strings on the fly, in this order:
+/// whitespace is collapsed, punctuation is skipped, accents are stripped and letters
+/// are lowercased.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{CompareOptions, StrExt};
+///
+/// let options = CompareOptions::new()
+///     .case_insensitive()
+///     .accent_insensitive()
+///     .collapse_whitespace()
+///     .ignore_punctuation();
+///
+/// assert_eq!("  Crème   Brûlée! ".levenshtein_distance("creme brulee"), 11);
+/// assert_eq!("  Crème   Brûlée! ".levenshtein_distance_with("creme brulee", options), 0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompareOptions {
+    /// Whether letters match regardless of their case.
+    pub case_insensitive: bool,
+    /// Whether accented Latin letters match their base letter, combining diacritical
+    /// marks being ignored altogether.
+    pub accent_insensitive: bool,
+    /// Whether runs of whitespace match a single space, whitespace at the edges being ignored.
+    pub collapse_whitespace: bool,
+    /// Whether ASCII punctuation and symbols, along with the common Unicode punctuation,
+    /// are ignored.
+    pub ignore_punctuation: bool,
+}
+
+impl CompareOptions {
+    /// Creates the options of an exact comparison.
+    pub const fn new() -> Self {
+        Self {
+            case_insensitive: false,
+            accent_insensitive: false,
+            collapse_whitespace: false,
+            ignore_punctuation: false,
+        }
+    }
+
+    /// Returns the options matching letters regardless of their case.
+    pub const fn case_insensitive(self) -> Self {
+        Self {
+            case_insensitive: true,
+            ..self
+        }
+    }
+
+    /// Returns the options matching accented Latin letters with their base letter.
+    pub const fn accent_insensitive(self) -> Self {
+        Self {
+            accent_insensitive: true,
+            ..self
+        }
+    }
+
+    /// Returns the options matching runs of whitespace with a single space.
+    pub const fn collapse_whitespace(self) -> Self {
+        Self {
+            collapse_whitespace: true,
+            ..self
+        }
+    }
+
+    /// Returns the options ignoring punctuation.
+    pub const fn ignore_punctuation(self) -> Self {
+        Self {
+            ignore_punctuation: true,
+            ..self
+        }
+    }
+
+    /// Returns `true` if the options compare strings exactly as they are.
+    pub(crate) fn is_exact(&self) -> bool {
+        *self == Self::new()
+    }
+
+    /// Returns an iterator over the chars of `s` normalized according to the options.
+    pub(crate) fn chars<'a>(&self, s: &'a str) -> NormalizedChars<'a> {
+        NormalizedChars {
+            options: *self,
+            chars: s.chars(),
+            lowercase: None,
+            pending: None,
+            space: false,
+            started: false,
+        }
+    }
+}
+
+/// An iterator over the chars of a string normalized according to `CompareOptions`.
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedChars<'a> {
+    options: CompareOptions,
+    chars: str::Chars<'a>,
+    /// The remaining chars of the lowercase mapping of the last char.
+    lowercase: Option<ToLowercase>,
+    /// The char to yield after the space collapsing the whitespace preceding it.
+    pending: Option<char>,
+    /// Whether whitespace has been skipped since the last char.
+    space: bool,
+    /// Whether any char has been yielded.
+    started: bool,
+}
+
+impl Iterator for NormalizedChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let options = self.options;
+
+        loop {
+            if let Some(lowercase) = &mut self.lowercase {
+                match lowercase.next() {
+                    // e.g. the lowercase mapping of 'İ' is 'i' followed by a combining dot.
+                    Some(c) if options.accent_insensitive && is_combining_mark(c) => continue,
+                    Some(c) => return Some(c),
+                    None => self.lowercase = None,
+                }
+            }
+
+            let c = match self.pending.take() {
+                Some(c) => c,
+                None => self.chars.next()?,
+            };
+
+            if options.collapse_whitespace && c.is_whitespace() {
+                self.space = self.started;
+                continue;
+            }
+
+            if options.ignore_punctuation && is_punctuation(c)
+                || options.accent_insensitive && is_combining_mark(c)
+            {
+                continue;
+            }
+
+            if self.space {
+                self.space = false;
+                self.pending = Some(c);
+                return Some(' ');
+            }
+
+            self.started = true;
+            let c = if options.accent_insensitive {
+                strip_accent(c)
+            } else {
+                c
+            };
+
+            if options.case_insensitive && !c.is_ascii() {
+                self.lowercase = Some(c.to_lowercase());
+            } else if options.case_insensitive {
+                return Some(c.to_ascii_lowercase());
+            } else {
+                return Some(c);
+            }
+        }
+    }
+}
+
+impl FusedIterator for NormalizedChars<'_> {}
+
+/// Returns `true` if `c` is a combining diacritical mark.
+fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36f}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{fe20}'..='\u{fe2f}'
+    )
+}
+
+/// Returns `true` if `c` is ASCII punctuation or a symbol, or common Unicode punctuation.
+fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(
+            c,
+            '\u{a1}'
+                | '\u{a7}'
+                | '\u{ab}'
+                | '\u{b6}'
+                | '\u{b7}'
+                | '\u{bb}'
+                | '\u{bf}'
+                | '\u{2010}'..='\u{2027}'
+                | '\u{2030}'..='\u{205e}'
+                | '\u{3001}'..='\u{3003}'
+                | '\u{3008}'..='\u{3011}'
+        )
+}
+
+/// Returns the base letter of `c` if it is an accented Latin letter, otherwise `c` itself.
+fn strip_accent(c: char) -> char {
+    let base = match c {
+        '\u{c0}'..='\u{24f}' => LATIN[c as usize - 0xc0],
+        '\u{1e00}'..='\u{1eff}' => LATIN_EXTENDED_ADDITIONAL[c as usize - 0x1e00],
+        _ => return c,
+    };
+
+    if base == b'_' {
+        c
+    } else {
+        char::from(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{strip_accent, CompareOptions};
+
+    #[test]
+    fn strip_accents() {
+        const SEED: [(char, char); 10] = [
+            ('a', 'a'),
+            ('À', 'A'),
+            ('é', 'e'),
+            ('×', '×'),
+            ('ß', 'ß'),
+            ('Ł', 'L'),
+            ('ž', 'z'),
+            ('ǘ', 'u'),
+            ('ệ', 'e'),
+            ('ỹ', 'y'),
+        ];
+
+        for (sut, expected) in SEED {
+            assert_eq!(strip_accent(sut), expected, "{sut}");
+        }
+    }
+
+    #[test]
+    fn chars() {
+        let exact = CompareOptions::new();
+        let case = CompareOptions::new().case_insensitive();
+        let accent = CompareOptions::new().accent_insensitive();
+        let whitespace = CompareOptions::new().collapse_whitespace();
+        let punctuation = CompareOptions::new().ignore_punctuation();
+        let all = case
+            .accent_insensitive()
+            .collapse_whitespace()
+            .ignore_punctuation();
+
+        let seed = [
+            ("", all, ""),
+            (" Déjà\tVu ", exact, " Déjà\tVu "),
+            (" Déjà\tVu ", case, " déjà\tvu "),
+            (" Déjà\tVu ", accent, " Deja\tVu "),
+            (" Déjà\tVu ", whitespace, "Déjà Vu"),
+            (" Déjà\tVu ", all, "deja vu"),
+            ("cafe\u{301}", accent, "cafe"),
+            ("İstanbul", case, "i\u{307}stanbul"),
+            ("İstanbul", all, "istanbul"),
+            ("  \t\n ", whitespace, ""),
+            ("a - b", punctuation, "a  b"),
+            ("a - b", all, "a b"),
+            ("«Hello, world!»", punctuation, "Hello world"),
+            ("don’t … stop", all, "dont stop"),
+        ];
+
+        for (sut, options, expected) in seed {
+            assert_eq!(options.chars(sut).collect::<String>(), expected, "{sut}");
+        }
+    }
+}
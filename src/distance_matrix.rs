@@ -0,0 +1,243 @@
+//! Pairwise distances between the strings of a collection, computed in parallel
+//! and stored as a condensed upper-triangular matrix.
+
+use std::num::NonZeroUsize;
+use std::thread;
+
+use crate::StringMetric;
+
+/// The least number of pairs worth spawning a worker thread for.
+const MIN_PAIRS_PER_WORKER: usize = 1024;
+
+/// The distances between every pair of strings of a collection, as computed by
+/// `distance_matrix` and `distance_matrix_within`.
+///
+/// Since distances are symmetric and the distance of a string from itself is zero, only the
+/// upper triangle of the matrix is stored: the distances between the `i`-th string and the
+/// following ones, for each `i` in order, as in the condensed matrices of SciPy's `pdist`.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{distance_matrix, Levenshtein};
+///
+/// let matrix = distance_matrix(&["kitten", "sitting", "mitten"], Levenshtein);
+/// assert_eq!(matrix.len(), 3);
+/// assert_eq!(matrix.as_condensed(), [3, 1, 3]);
+/// assert_eq!(matrix.get(2, 0), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceMatrix<D> {
+    len: usize,
+    distances: Vec<D>,
+}
+
+impl<D: Copy> DistanceMatrix<D> {
+    /// Returns the number of strings whose distances are stored in the matrix.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the matrix stores the distances of no strings.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the distance between the `i`-th and the `j`-th strings.
+    ///
+    /// # Panics
+    /// Panics if `i` and `j` are equal or if either of them is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> D {
+        assert!(i != j, "the distance of a string from itself is not stored");
+        assert!(i < self.len && j < self.len, "index out of bounds");
+
+        self.distances[condensed_index(self.len, i.min(j), i.max(j))]
+    }
+
+    /// Returns the condensed matrix, that is the distances between each string and
+    /// the following ones.
+    pub fn as_condensed(&self) -> &[D] {
+        &self.distances
+    }
+
+    /// Returns an iterator over the pairs of indices of distinct strings, each pair
+    /// `(i, j)` with `i < j`, along with their distance.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, D)> + '_ {
+        (0..self.len)
+            .flat_map(move |i| (i + 1..self.len).map(move |j| (i, j)))
+            .zip(&self.distances)
+            .map(|((i, j), &distance)| (i, j, distance))
+    }
+}
+
+/// Computes the distances by `metric` between every pair of `strings`,
+/// spreading the computations over as many threads as the available parallelism.
+pub fn distance_matrix<S, M>(strings: &[S], metric: M) -> DistanceMatrix<M::Distance>
+where
+    S: AsRef<str> + Sync,
+    M: StringMetric + Sync,
+    M::Distance: Send,
+{
+    let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    compute(strings.len(), workers, |i, j| {
+        metric.distance(strings[i].as_ref(), strings[j].as_ref())
+    })
+}
+
+/// Computes the distances by `metric` between every pair of `strings`, marking with `None`
+/// the pairs whose distance exceeds `max` rather than computing it exactly, which is
+/// considerably faster with metrics giving up early, such as `Levenshtein`.
+/// The computations are spread over as many threads as the available parallelism.
+pub fn distance_matrix_within<S, M>(
+    strings: &[S],
+    metric: M,
+    max: M::Distance,
+) -> DistanceMatrix<Option<M::Distance>>
+where
+    S: AsRef<str> + Sync,
+    M: StringMetric + Sync,
+    M::Distance: Send + Sync,
+{
+    let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    compute(strings.len(), workers, |i, j| {
+        metric.distance_within(strings[i].as_ref(), strings[j].as_ref(), max)
+    })
+}
+
+/// Returns the index in the condensed matrix of `len` strings of the pair `(i, j)`, `i < j`.
+fn condensed_index(len: usize, i: usize, j: usize) -> usize {
+    // the rows before `i` hold `len - 1 + len - 2 + ... + len - i` distances.
+    i * (2 * len - i - 1) / 2 + (j - i - 1)
+}
+
+/// Computes the condensed matrix of `len` strings, splitting the pairs in contiguous
+/// chunks of about the same size computed by at most `workers` threads.
+fn compute<D, F>(len: usize, workers: usize, distance: F) -> DistanceMatrix<D>
+where
+    D: Send,
+    F: Fn(usize, usize) -> D + Sync,
+{
+    let pairs = len * len.saturating_sub(1) / 2;
+    let workers = workers.min(pairs / MIN_PAIRS_PER_WORKER).max(1);
+    let chunk = (pairs + workers - 1) / workers;
+
+    // computes the distances of the pairs from the `start`-th to the `end`-th.
+    let compute_chunk = |start: usize, end: usize| {
+        let mut distances = Vec::with_capacity(end - start);
+        if start == end {
+            return distances;
+        }
+
+        let (mut i, mut row_start) = (0, 0);
+
+        // finds the row of the first pair.
+        while row_start + (len - i - 1) <= start {
+            row_start += len - i - 1;
+            i += 1;
+        }
+
+        let mut j = i + 1 + (start - row_start);
+        for _ in start..end {
+            distances.push(distance(i, j));
+
+            j += 1;
+            if j == len {
+                i += 1;
+                j = i + 1;
+            }
+        }
+
+        distances
+    };
+
+    let distances = if workers == 1 {
+        compute_chunk(0, pairs)
+    } else {
+        thread::scope(|scope| {
+            let handles = (0..pairs)
+                .step_by(chunk)
+                .map(|start| {
+                    let compute_chunk = &compute_chunk;
+                    scope.spawn(move || compute_chunk(start, usize::min(start + chunk, pairs)))
+                })
+                .collect::<Vec<_>>();
+
+            let mut distances = Vec::with_capacity(pairs);
+            for handle in handles {
+                distances.extend(handle.join().unwrap());
+            }
+
+            distances
+        })
+    };
+
+    DistanceMatrix { len, distances }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute, distance_matrix, distance_matrix_within, DistanceMatrix};
+    use crate::{Hamming, JaroWinkler, Levenshtein, StringMetric};
+
+    const WORDS: [&str; 9] = [
+        "book", "books", "cake", "boo", "", "cook", "cape", "kitten", "sitting",
+    ];
+
+    fn brute_force<D>(len: usize, distance: impl Fn(usize, usize) -> D) -> Vec<D> {
+        (0..len)
+            .flat_map(|i| (i + 1..len).map(move |j| (i, j)))
+            .map(|(i, j)| distance(i, j))
+            .collect()
+    }
+
+    #[test]
+    fn condensed() {
+        for len in 0..40 {
+            let matrix = compute(len, 1, |i, j| (i, j));
+            assert_eq!(matrix.len(), len);
+            assert_eq!(matrix.as_condensed(), brute_force(len, |i, j| (i, j)));
+
+            for (i, j, distance) in matrix.iter() {
+                assert_eq!(matrix.get(i, j), distance);
+                assert_eq!(matrix.get(j, i), (i, j));
+            }
+        }
+
+        // enough pairs to be split among many workers, 19900 pairs.
+        for workers in [2, 3, 7, 19, 64] {
+            let matrix = compute(200, workers, |i, j| (i, j));
+            assert_eq!(matrix.as_condensed(), brute_force(200, |i, j| (i, j)));
+        }
+    }
+
+    #[test]
+    fn distances() {
+        let matrix = distance_matrix(&WORDS, Levenshtein);
+        let expected = brute_force(WORDS.len(), |i, j| Levenshtein.distance(WORDS[i], WORDS[j]));
+        assert_eq!(matrix.as_condensed(), expected);
+        assert_eq!(matrix.get(0, 1), 1);
+
+        let matrix = distance_matrix(&WORDS, JaroWinkler::default());
+        assert_eq!(
+            matrix.get(7, 8),
+            JaroWinkler::default().distance("kitten", "sitting")
+        );
+
+        let matrix = distance_matrix_within(&WORDS, Levenshtein, 1);
+        let expected = brute_force(WORDS.len(), |i, j| {
+            Some(Levenshtein.distance(WORDS[i], WORDS[j])).filter(|&d| d <= 1)
+        });
+        assert_eq!(matrix.as_condensed(), expected);
+        assert_eq!(matrix.get(0, 3), Some(1));
+        assert_eq!(matrix.get(7, 8), None);
+
+        let matrix = distance_matrix_within(&WORDS, Hamming, Some(1));
+        assert_eq!(matrix.get(2, 6), Some(Some(1)));
+        assert_eq!(matrix.get(0, 1), None);
+
+        let empty: DistanceMatrix<usize> = distance_matrix(&[] as &[&str], Levenshtein);
+        assert!(empty.is_empty());
+        assert!(distance_matrix(&["one"], Levenshtein)
+            .as_condensed()
+            .is_empty());
+    }
+}
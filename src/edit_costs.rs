@@ -0,0 +1,159 @@
+/// The `EditCosts` trait describes how much each edit operation costs when computing
+/// a weighted edit distance (see `StrExt::weighted_levenshtein_distance`).
+///
+/// Costs are expected to be non-negative. Keeping a char unchanged is always free,
+/// so `substitution` is only ever called with two different chars.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{EditCosts, StrExt};
+///
+/// struct CheapVowels;
+///
+/// impl EditCosts for CheapVowels {
+///     fn insertion(&self, _: char) -> f64 {
+///         1.0
+///     }
+///
+///     fn deletion(&self, _: char) -> f64 {
+///         1.0
+///     }
+///
+///     fn substitution(&self, from: char, to: char) -> f64 {
+///         if "aeiou".contains(from) && "aeiou".contains(to) {
+///             0.25
+///         } else {
+///             1.0
+///         }
+///     }
+/// }
+///
+/// assert_eq!("color".weighted_levenshtein_distance("colur", &CheapVowels), 0.25);
+/// ```
+pub trait EditCosts {
+    /// Returns the cost of inserting `c`.
+    fn insertion(&self, c: char) -> f64;
+
+    /// Returns the cost of deleting `c`.
+    fn deletion(&self, c: char) -> f64;
+
+    /// Returns the cost of substituting `from` with `to`.
+    fn substitution(&self, from: char, to: char) -> f64;
+}
+
+/// Every edit operation costs `1.0`, making the weighted distance
+/// equivalent to the plain Levenshtein distance.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UniformCosts;
+
+impl EditCosts for UniformCosts {
+    fn insertion(&self, _: char) -> f64 {
+        1.0
+    }
+
+    fn deletion(&self, _: char) -> f64 {
+        1.0
+    }
+
+    fn substitution(&self, _: char, _: char) -> f64 {
+        1.0
+    }
+}
+
+/// Every edit operation costs `1.0`, except substitutions between case variants
+/// of the same char (e.g. `'a'` and `'A'`) which cost `case_substitution`.
+///
+/// The default value of `case_substitution` is `0.0`, which makes comparisons
+/// entirely case-insensitive.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CaseInsensitiveCosts {
+    pub case_substitution: f64,
+}
+
+impl EditCosts for CaseInsensitiveCosts {
+    fn insertion(&self, _: char) -> f64 {
+        1.0
+    }
+
+    fn deletion(&self, _: char) -> f64 {
+        1.0
+    }
+
+    fn substitution(&self, from: char, to: char) -> f64 {
+        if from.to_lowercase().eq(to.to_lowercase()) {
+            self.case_substitution
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Every edit operation costs `1.0`, except substitutions between chars that sit next
+/// to each other on a QWERTY keyboard (e.g. `'s'` and `'d'`) which cost `adjacent_substitution`.
+///
+/// Letters are looked up ignoring their case. The default value of `adjacent_substitution` is `0.5`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyboardCosts {
+    pub adjacent_substitution: f64,
+}
+
+impl KeyboardCosts {
+    const ROWS: [&'static [u8]; 4] = [
+        b"1234567890-=",
+        b"qwertyuiop[]",
+        b"asdfghjkl;'",
+        b"zxcvbnm,./",
+    ];
+
+    fn position(c: char) -> Option<(usize, usize)> {
+        if !c.is_ascii() {
+            return None;
+        }
+
+        let c = c.to_ascii_lowercase() as u8;
+        Self::ROWS
+            .iter()
+            .enumerate()
+            .find_map(|(row, keys)| keys.iter().position(|&k| k == c).map(|col| (row, col)))
+    }
+
+    fn are_adjacent(a: char, b: char) -> bool {
+        match (Self::position(a), Self::position(b)) {
+            // rows are staggered: each key touches the keys at the same column and at the
+            // next column in the row above, and at the same and previous column in the row below.
+            (Some((ra, ca)), Some((rb, cb))) => match rb as isize - ra as isize {
+                0 => ca.abs_diff(cb) == 1,
+                -1 => cb == ca || cb == ca + 1,
+                1 => ca == cb || ca == cb + 1,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl Default for KeyboardCosts {
+    fn default() -> Self {
+        Self {
+            adjacent_substitution: 0.5,
+        }
+    }
+}
+
+impl EditCosts for KeyboardCosts {
+    fn insertion(&self, _: char) -> f64 {
+        1.0
+    }
+
+    fn deletion(&self, _: char) -> f64 {
+        1.0
+    }
+
+    fn substitution(&self, from: char, to: char) -> f64 {
+        if Self::are_adjacent(from, to) {
+            self.adjacent_substitution
+        } else {
+            1.0
+        }
+    }
+}
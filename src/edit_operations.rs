@@ -0,0 +1,175 @@
+use std::ops::Range;
+
+/// A single edit operation of an edit script turning a source string into a target string,
+/// as computed by `StrExt::edit_operations`.
+///
+/// Each operation spans at most one char in each string, `source` and `target` are the
+/// byte ranges of those chars in the respective strings. Operations that do not consume
+/// a char of one of the strings hold an empty range at the position where they apply.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{EditOperation, StrExt, StringExt};
+///
+/// let operations = "cat".edit_operations("cart");
+/// assert_eq!(
+///     operations,
+///     [
+///         EditOperation::Keep { source: 0..1, target: 0..1 },
+///         EditOperation::Keep { source: 1..2, target: 1..2 },
+///         EditOperation::Insert { source: 2..2, target: 2..3 },
+///         EditOperation::Keep { source: 2..3, target: 3..4 },
+///     ]
+/// );
+///
+/// let mut s = String::from("cat");
+/// s.apply_edit_operations("cart", &operations);
+/// assert_eq!(s, "cart");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EditOperation {
+    /// The char of the source is kept as it is in the target.
+    Keep {
+        source: Range<usize>,
+        target: Range<usize>,
+    },
+    /// The char of the target is inserted in the source.
+    Insert {
+        source: Range<usize>,
+        target: Range<usize>,
+    },
+    /// The char of the source is deleted.
+    Delete {
+        source: Range<usize>,
+        target: Range<usize>,
+    },
+    /// The char of the source is substituted with the char of the target.
+    Substitute {
+        source: Range<usize>,
+        target: Range<usize>,
+    },
+}
+
+impl EditOperation {
+    /// Returns the byte range of the operation in the source string.
+    pub fn source(&self) -> Range<usize> {
+        match self {
+            Self::Keep { source, .. }
+            | Self::Insert { source, .. }
+            | Self::Delete { source, .. }
+            | Self::Substitute { source, .. } => source.clone(),
+        }
+    }
+
+    /// Returns the byte range of the operation in the target string.
+    pub fn target(&self) -> Range<usize> {
+        match self {
+            Self::Keep { target, .. }
+            | Self::Insert { target, .. }
+            | Self::Delete { target, .. }
+            | Self::Substitute { target, .. } => target.clone(),
+        }
+    }
+
+    /// Returns `true` if the operation edits the source string, that is if it is not a `Keep`.
+    pub fn is_edit(&self) -> bool {
+        !matches!(self, Self::Keep { .. })
+    }
+}
+
+/// Computes the Levenshtein edit script turning `source` into `target`, whose first chars
+/// are respectively at byte `source_offset` and `target_offset` of the original strings.
+/// The operations are appended to `operations`.
+pub(crate) fn edit_operations(
+    source: &str,
+    target: &str,
+    source_offset: usize,
+    target_offset: usize,
+    operations: &mut Vec<EditOperation>,
+) {
+    // chars paired with their byte ranges in the original strings.
+    let ranges = |s: &str, offset: usize| {
+        s.char_indices()
+            .map(|(i, c)| (c, offset + i..offset + i + c.len_utf8()))
+            .collect::<Vec<_>>()
+    };
+
+    let (source, target) = (ranges(source, source_offset), ranges(target, target_offset));
+    let columns = target.len() + 1;
+
+    // the whole distance matrix is needed to trace back the edit script.
+    let mut costs = vec![0; (source.len() + 1) * columns];
+    for (j, cost) in costs[..columns].iter_mut().enumerate() {
+        *cost = j;
+    }
+
+    for i in 1..=source.len() {
+        costs[i * columns] = i;
+
+        for j in 1..columns {
+            let corner = costs[(i - 1) * columns + j - 1];
+
+            costs[i * columns + j] = if source[i - 1].0 == target[j - 1].0 {
+                corner
+            } else {
+                1 + usize::min(
+                    usize::min(costs[(i - 1) * columns + j], costs[i * columns + j - 1]),
+                    corner,
+                )
+            };
+        }
+    }
+
+    // walk back from the last cell, favouring keeps and substitutions over deletions
+    // and deletions over insertions.
+    let start = operations.len();
+    let (mut i, mut j) = (source.len(), target.len());
+
+    while i > 0 || j > 0 {
+        let cost = costs[i * columns + j];
+
+        if i > 0
+            && j > 0
+            && source[i - 1].0 == target[j - 1].0
+            && cost == costs[(i - 1) * columns + j - 1]
+        {
+            operations.push(EditOperation::Keep {
+                source: source[i - 1].1.clone(),
+                target: target[j - 1].1.clone(),
+            });
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && cost == costs[(i - 1) * columns + j - 1] + 1 {
+            operations.push(EditOperation::Substitute {
+                source: source[i - 1].1.clone(),
+                target: target[j - 1].1.clone(),
+            });
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && cost == costs[(i - 1) * columns + j] + 1 {
+            let at = if j > 0 {
+                target[j - 1].1.end
+            } else {
+                target_offset
+            };
+            operations.push(EditOperation::Delete {
+                source: source[i - 1].1.clone(),
+                target: at..at,
+            });
+            i -= 1;
+        } else {
+            let at = if i > 0 {
+                source[i - 1].1.end
+            } else {
+                source_offset
+            };
+            operations.push(EditOperation::Insert {
+                source: at..at,
+                target: target[j - 1].1.clone(),
+            });
+            j -= 1;
+        }
+    }
+
+    operations[start..].reverse();
+}
@@ -0,0 +1,228 @@
+//! Fuzzy matching of queries as subsequences of strings, scored the way fzf does
+//! (see <https://github.com/junegunn/fzf/blob/master/src/algo/algo.go>).
+//!
+//! Each matched char scores a fixed amount, plus a bonus when it starts a word, a camelCase
+//! hump or a number, or when it continues a run of consecutive matches, while the chars
+//! skipped between matches are penalized as affine gaps.
+
+const SCORE_MATCH: i64 = 16;
+const SCORE_GAP_START: i64 = -3;
+const SCORE_GAP_EXTENSION: i64 = -1;
+const BONUS_BOUNDARY: i64 = SCORE_MATCH / 2;
+const BONUS_NON_WORD: i64 = SCORE_MATCH / 2;
+const BONUS_CAMEL_123: i64 = BONUS_BOUNDARY + SCORE_GAP_EXTENSION;
+const BONUS_CONSECUTIVE: i64 = -(SCORE_GAP_START + SCORE_GAP_EXTENSION);
+const BONUS_FIRST_CHAR_MULTIPLIER: i64 = 2;
+
+/// How the case of letters is taken into account by `StrExt::fuzzy_match_with`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseMatching {
+    /// Case-insensitive unless the query contains uppercase letters.
+    #[default]
+    Smart,
+    /// Letters match only if they have the same case.
+    Sensitive,
+    /// Letters match regardless of their case.
+    Insensitive,
+}
+
+/// A match of a query as a subsequence of a string (see `StrExt::fuzzy_match`).
+///
+/// # Examples
+/// ```rust
+/// use string_more::StrExt;
+///
+/// let text = "git commit --amend";
+/// let fuzzy_match = text.fuzzy_match("gca").unwrap();
+/// assert_eq!(fuzzy_match.positions, [0, 4, 13]);
+/// assert!(fuzzy_match.score > "magic carpet".fuzzy_match("gca").unwrap().score);
+/// assert_eq!(text.fuzzy_highlight(&fuzzy_match.positions, '[', ']'), "[g]it [c]ommit --[a]mend");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FuzzyMatch {
+    /// The score of the match, the higher the better.
+    pub score: i64,
+    /// The byte indices of the matched chars in the string, in increasing order.
+    pub positions: Vec<usize>,
+}
+
+/// The classes of chars that determine the bonuses of the matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    NonWord,
+    Lower,
+    Upper,
+    Number,
+}
+
+impl Class {
+    fn of(c: char) -> Self {
+        if c.is_lowercase() {
+            Self::Lower
+        } else if c.is_uppercase() {
+            Self::Upper
+        } else if c.is_numeric() {
+            Self::Number
+        } else if c.is_alphabetic() {
+            // letters with no case, as in most scripts other than Latin, Greek and Cyrillic.
+            Self::Lower
+        } else {
+            Self::NonWord
+        }
+    }
+}
+
+/// Returns the bonus of matching a char of class `current` following a char of class `previous`.
+fn bonus(previous: Class, current: Class) -> i64 {
+    match (previous, current) {
+        (Class::NonWord, Class::Lower | Class::Upper | Class::Number) => BONUS_BOUNDARY,
+        (Class::Lower, Class::Upper) | (Class::Lower | Class::Upper, Class::Number) => {
+            BONUS_CAMEL_123
+        }
+        (_, Class::NonWord) => BONUS_NON_WORD,
+        _ => 0,
+    }
+}
+
+/// The best match of a query char at a char of the text.
+#[derive(Debug, Clone, Copy)]
+struct Cell {
+    score: i64,
+    /// The column at which the previous query char is matched.
+    from: usize,
+    /// The column at which the run of consecutive matches ending at the cell begins.
+    run: usize,
+}
+
+/// Finds the best match of `query` as a subsequence of `text`.
+pub(crate) fn fuzzy_match(text: &str, query: &str, case: CaseMatching) -> Option<FuzzyMatch> {
+    let sensitive = match case {
+        CaseMatching::Smart => query.chars().any(char::is_uppercase),
+        CaseMatching::Sensitive => true,
+        CaseMatching::Insensitive => false,
+    };
+    let matches =
+        |a: char, b: char| a == b || (!sensitive && a.to_lowercase().eq(b.to_lowercase()));
+
+    let query: Vec<char> = query.chars().collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    // the chars of the text, along with their byte indices and the bonuses of matching them.
+    // the text is considered to be preceded by a non-word char, so that its start is a boundary.
+    let mut previous = Class::NonWord;
+    let text: Vec<(char, usize, i64)> = text
+        .char_indices()
+        .map(|(i, c)| {
+            let class = Class::of(c);
+            let bonus = bonus(previous, class);
+            previous = class;
+            (c, i, bonus)
+        })
+        .collect();
+
+    // bail out before allocating the matrix if the query is not a subsequence of the text,
+    // while finding the first column at which each query char may be matched.
+    let mut first = Vec::with_capacity(query.len());
+    let mut column = 0;
+    for &q in &query {
+        column += text[column..].iter().position(|&(c, ..)| matches(c, q))?;
+        first.push(column);
+        column += 1;
+    }
+
+    let columns = text.len();
+    let mut cells: Vec<Option<Cell>> = vec![None; query.len() * columns];
+
+    for (i, &q) in query.iter().enumerate() {
+        // the best match of the previous query char followed by a gap up to the current column.
+        // it is accumulated since the first column following a match of the previous query char.
+        let mut gap: Option<(i64, usize)> = None;
+        let start = i.checked_sub(1).map_or(first[0], |i| first[i] + 1);
+
+        for j in start..columns {
+            if i > 0 && j >= 2 {
+                let extend = gap.map(|(score, from)| (score + SCORE_GAP_EXTENSION, from));
+                let open =
+                    cells[(i - 1) * columns + j - 2].map(|c| (c.score + SCORE_GAP_START, j - 2));
+                gap = match (open, extend) {
+                    (Some(open), Some(extend)) if extend.0 >= open.0 => Some(extend),
+                    (None, extend) => extend,
+                    (open, _) => open,
+                };
+            }
+
+            let (c, _, bonus) = text[j];
+            if !matches(c, q) {
+                continue;
+            }
+
+            if i == 0 {
+                cells[j] = Some(Cell {
+                    score: SCORE_MATCH + bonus * BONUS_FIRST_CHAR_MULTIPLIER,
+                    from: j,
+                    run: j,
+                });
+                continue;
+            }
+
+            let mut best = gap.map(|(score, from)| Cell {
+                score: score + SCORE_MATCH + bonus,
+                from,
+                run: j,
+            });
+
+            // on ties, consecutive matches are favoured over gaps.
+            if let Some(diagonal) = cells[(i - 1) * columns + j - 1] {
+                // a boundary breaks the run, so that the following chars inherit its bonus.
+                let run = if bonus >= BONUS_BOUNDARY {
+                    j
+                } else {
+                    diagonal.run
+                };
+                let bonus = if run == j {
+                    bonus
+                } else {
+                    bonus.max(BONUS_CONSECUTIVE).max(text[run].2)
+                };
+                let score = diagonal.score + SCORE_MATCH + bonus;
+
+                if best.map_or(true, |best| score >= best.score) {
+                    best = Some(Cell {
+                        score,
+                        from: j - 1,
+                        run,
+                    });
+                }
+            }
+
+            cells[i * columns + j] = best;
+        }
+    }
+
+    // on ties, the leftmost match is favoured.
+    let last = (query.len() - 1) * columns;
+    let (mut column, end) = cells[last..]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, cell)| cell.map(|cell| (j, cell)))
+        .fold(None, |best: Option<(usize, Cell)>, (j, cell)| match best {
+            Some((_, best_cell)) if best_cell.score >= cell.score => best,
+            _ => Some((j, cell)),
+        })?;
+
+    let mut positions = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        positions[i] = text[column].1;
+        column = cells[i * columns + column]?.from;
+    }
+
+    Some(FuzzyMatch {
+        score: end.score,
+        positions,
+    })
+}
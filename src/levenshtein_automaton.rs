@@ -0,0 +1,260 @@
+//! Levenshtein automata, as described by K. U. Schulz and S. Mihov in "Fast string correction
+//! with Levenshtein automata" (2002).
+//!
+//! The automaton accepts the strings within `max` edits of a query. Its states are sets of
+//! positions `(i, e)`, each standing for the first `i` chars of the query matched with `e`
+//! edits, reduced to the positions not subsumed by others, so that they are computed lazily
+//! while reading a string one char at a time.
+
+/// A state of a `LevenshteinAutomaton`, reached after reading a prefix of a string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LevenshteinState {
+    /// The positions `(i, e)` of the state, sorted and not subsumed by each other.
+    positions: Vec<(usize, usize)>,
+}
+
+/// A Levenshtein automaton, accepting the strings whose Levenshtein distance from a query
+/// does not exceed `max`, as computed by `StrExt::levenshtein_automaton`.
+///
+/// The automaton reads strings one char at a time, giving up as soon as no continuation
+/// can be within `max` edits of the query: a sorted dictionary or a trie can be walked
+/// stepping through the common prefixes of the words only once and skipping the words
+/// that start with a rejected prefix.
+///
+/// # Examples
+/// ```rust
+/// use string_more::StrExt;
+///
+/// let automaton = "food".levenshtein_automaton(1);
+/// assert_eq!(automaton.distance("good"), Some(1));
+/// assert_eq!(automaton.distance("fodder"), None);
+///
+/// let words = ["flood", "fold", "foo", "food", "foods", "fool", "forward", "fox"];
+/// assert_eq!(
+///     automaton.search(words),
+///     [("flood", 1), ("fold", 1), ("foo", 1), ("food", 0), ("foods", 1), ("fool", 1)]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LevenshteinAutomaton {
+    query: Vec<char>,
+    max: usize,
+}
+
+impl LevenshteinAutomaton {
+    pub(crate) fn new(query: &str, max: usize) -> Self {
+        Self {
+            query: query.chars().collect(),
+            max,
+        }
+    }
+
+    /// Returns the maximal number of edits of the accepted strings.
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Returns the initial state, before reading any char.
+    pub fn start(&self) -> LevenshteinState {
+        LevenshteinState {
+            positions: vec![(0, 0)],
+        }
+    }
+
+    /// Returns the state reached by reading `c` from `state`,
+    /// or `None` if no string continuing with `c` can be accepted.
+    pub fn step(&self, state: &LevenshteinState, c: char) -> Option<LevenshteinState> {
+        let (query, max) = (&self.query, self.max);
+        let mut positions = Vec::with_capacity(2 * state.positions.len() + 1);
+
+        for &(i, e) in &state.positions {
+            if i < query.len() && query[i] == c {
+                positions.push((i + 1, e));
+            }
+
+            if e < max {
+                // `c` is inserted, or substitutes the next char of the query.
+                positions.push((i, e + 1));
+                if i < query.len() {
+                    positions.push((i + 1, e + 1));
+                }
+
+                // `j` chars of the query are deleted before matching `c`.
+                for j in 1..=max - e {
+                    if i + j < query.len() && query[i + j] == c {
+                        positions.push((i + j + 1, e + j));
+                    }
+                }
+            }
+        }
+
+        reduce(&mut positions);
+        (!positions.is_empty()).then_some(LevenshteinState { positions })
+    }
+
+    /// Returns the distance of the string read to reach `state` from the query,
+    /// or `None` if it exceeds `max`.
+    pub fn accepts(&self, state: &LevenshteinState) -> Option<usize> {
+        state
+            .positions
+            .iter()
+            .map(|&(i, e)| e + (self.query.len() - i))
+            .min()
+            .filter(|&distance| distance <= self.max)
+    }
+
+    /// Returns the Levenshtein distance between `word` and the query,
+    /// or `None` if it exceeds `max`.
+    pub fn distance(&self, word: &str) -> Option<usize> {
+        let mut state = self.start();
+
+        for c in word.chars() {
+            state = self.step(&state, c)?;
+        }
+
+        self.accepts(&state)
+    }
+
+    /// Returns the words within `max` edits of the query, along with their distance,
+    /// in the order of `words`. When the words are sorted, the chars of the prefix each word
+    /// shares with the previous one are read only once, and so are the rejected prefixes.
+    pub fn search<'a>(&self, words: impl IntoIterator<Item = &'a str>) -> Vec<(&'a str, usize)> {
+        let mut matches = Vec::new();
+        // the states reached by reading each prefix of the previous word, up to its
+        // whole length or to the first rejected prefix.
+        let mut states = vec![self.start()];
+        let mut previous = "";
+        let mut rejected = false;
+
+        for word in words {
+            // the length in chars of the prefix shared with the previous word.
+            let common = previous
+                .chars()
+                .zip(word.chars())
+                .take_while(|(l, r)| l == r)
+                .count();
+
+            // every word starting with a rejected prefix is rejected.
+            if rejected && common >= states.len() {
+                previous = word;
+                continue;
+            }
+
+            states.truncate(common + 1);
+            rejected = false;
+
+            for c in word.chars().skip(common) {
+                match self.step(&states[states.len() - 1], c) {
+                    Some(state) => states.push(state),
+                    None => {
+                        rejected = true;
+                        break;
+                    }
+                }
+            }
+
+            if !rejected {
+                if let Some(distance) = self.accepts(&states[states.len() - 1]) {
+                    matches.push((word, distance));
+                }
+            }
+
+            previous = word;
+        }
+
+        matches
+    }
+}
+
+/// Sorts `positions` removing duplicates and the positions subsumed by others, that is the
+/// positions `(j, f)` such that some `(i, e)` with `e < f` and `|j - i| <= f - e` exists:
+/// every string accepted from the former is accepted from the latter with no more edits.
+fn reduce(positions: &mut Vec<(usize, usize)>) {
+    positions.sort_unstable_by_key(|&(i, e)| (e, i));
+    positions.dedup();
+
+    let mut kept = 0;
+    for index in 0..positions.len() {
+        let (j, f) = positions[index];
+        let subsumed = positions[..kept]
+            .iter()
+            .any(|&(i, e)| e < f && i.max(j) - i.min(j) <= f - e);
+
+        if !subsumed {
+            positions[kept] = (j, f);
+            kept += 1;
+        }
+    }
+
+    positions.truncate(kept);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{LevenshteinAutomaton, LevenshteinState};
+    use crate::StrExt;
+
+    #[derive(Default)]
+    struct Trie {
+        word: Option<String>,
+        children: BTreeMap<char, Trie>,
+    }
+
+    impl Trie {
+        fn insert(&mut self, word: &str) {
+            let node = word
+                .chars()
+                .fold(self, |node, c| node.children.entry(c).or_default());
+            node.word = Some(word.to_string());
+        }
+
+        fn walk<'a>(
+            &'a self,
+            automaton: &LevenshteinAutomaton,
+            state: &LevenshteinState,
+            matches: &mut Vec<(&'a str, usize)>,
+        ) {
+            if let (Some(word), Some(distance)) = (&self.word, automaton.accepts(state)) {
+                matches.push((word, distance));
+            }
+
+            for (&c, child) in &self.children {
+                if let Some(state) = automaton.step(state, c) {
+                    child.walk(automaton, &state, matches);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn trie() {
+        const WORDS: [&str; 12] = [
+            "book", "books", "cake", "boo", "boon", "cook", "cape", "cart", "", "a", "bo", "b·ok",
+        ];
+
+        let mut trie = Trie::default();
+        for word in WORDS {
+            trie.insert(word);
+        }
+
+        for query in ["", "bo", "bool", "caqe", "zzzz"] {
+            for max in 0..3 {
+                let automaton = query.levenshtein_automaton(max);
+                let mut matches = Vec::new();
+                trie.walk(&automaton, &automaton.start(), &mut matches);
+                matches.sort_unstable();
+
+                let mut expected = WORDS
+                    .iter()
+                    .map(|word| (*word, query.levenshtein_distance(word)))
+                    .filter(|&(_, distance)| distance <= max)
+                    .collect::<Vec<_>>();
+                expected.sort_unstable();
+
+                assert_eq!(matches, expected, "{query} {max}");
+            }
+        }
+    }
+}
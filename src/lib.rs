@@ -12,13 +12,55 @@
 
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Write};
+use std::hash::Hash;
 use std::ops::Deref;
 
+use suffix_automaton::SuffixAutomaton;
+
+mod alignment;
+mod approx;
+mod ascii;
+mod bk_tree;
+mod compare;
+mod distance_matrix;
+mod edit_costs;
+mod edit_operations;
+mod fuzzy;
+mod grapheme;
+mod levenshtein_automaton;
+mod metric;
+mod myers;
+mod phonetic;
+mod qgram;
+mod ratcliff_obershelp;
+mod suffix_automaton;
+mod sym_spell;
+mod unit;
+
+pub use alignment::{Alignment, AlignmentScores};
+pub use approx::ApproxMatches;
+pub use bk_tree::{BkDistance, BkTree};
+pub use compare::CompareOptions;
+pub use distance_matrix::{distance_matrix, distance_matrix_within, DistanceMatrix};
+pub use edit_costs::{CaseInsensitiveCosts, EditCosts, KeyboardCosts, UniformCosts};
+pub use edit_operations::EditOperation;
+pub use fuzzy::{CaseMatching, FuzzyMatch};
+pub use levenshtein_automaton::{LevenshteinAutomaton, LevenshteinState};
+pub use metric::{
+    ByUnit, Cosine, DamerauLevenshtein, Hamming, Jaccard, Jaro, JaroWinkler, Levenshtein,
+    LongestCommonSubsequence, LongestCommonSubstring, Normalized, OptimalStringAlignment, Overlap,
+    RatcliffObershelp, SorensenDice, StringMetric, WeightedLevenshtein,
+};
+pub use qgram::QGram;
+pub use ratcliff_obershelp::{MatchingBlock, Opcode};
+pub use sym_spell::SymSpell;
+pub use unit::Unit;
+
 mod sailed {
     pub trait Sailed {}
 
-    pub trait HzMap: Default {
-        fn incr(&mut self, key: char);
+    pub trait HzMap<K>: Default {
+        fn incr(&mut self, key: K);
     }
 }
 
@@ -27,14 +69,14 @@ impl sailed::Sailed for &str {}
 impl sailed::Sailed for &mut str {}
 impl sailed::Sailed for String {}
 
-impl sailed::HzMap for BTreeMap<char, usize> {
-    fn incr(&mut self, key: char) {
+impl<K: Ord> sailed::HzMap<K> for BTreeMap<K, usize> {
+    fn incr(&mut self, key: K) {
         self.entry(key).and_modify(|n| *n += 1).or_insert(1);
     }
 }
 
-impl sailed::HzMap for HashMap<char, usize> {
-    fn incr(&mut self, key: char) {
+impl<K: Hash + Eq> sailed::HzMap<K> for HashMap<K, usize> {
+    fn incr(&mut self, key: K) {
         self.entry(key).and_modify(|n| *n += 1).or_insert(1);
     }
 }
@@ -110,19 +152,318 @@ pub trait StrExt: sailed::Sailed {
     /// The strings may have different lengths.
     fn levenshtein_distance(&self, other: &str) -> usize;
 
+    /// Computes the Levenshtein distance between the strings split in the given `unit`.
+    fn levenshtein_distance_by(&self, other: &str, unit: Unit) -> usize;
+
+    /// Computes the Levenshtein distance between the strings normalized according to `options`.
+    fn levenshtein_distance_with(&self, other: &str, options: CompareOptions) -> usize;
+
+    /// Computes the Levenshtein distance between the strings if it does not exceed `max`,
+    /// otherwise this function returns `None`.
+    /// The computation gives up as soon as the distance is known to be greater than `max`,
+    /// making it considerably faster than `levenshtein_distance` when `max` is small.
+    fn levenshtein_distance_within(&self, other: &str, max: usize) -> Option<usize>;
+
+    /// Builds the Levenshtein automaton accepting the strings whose Levenshtein distance
+    /// from `self` does not exceed `max`, which finds the words of a sorted dictionary or
+    /// of a trie within `max` edits without computing the distance of each word.
+    fn levenshtein_automaton(&self, max: usize) -> LevenshteinAutomaton;
+
+    /// Returns an iterator over the approximate occurrences of `pattern` in the string, that is
+    /// the substrings whose Levenshtein distance from `pattern` does not exceed `max`, along with
+    /// their byte range and distance. The ranges lie on char boundaries.
+    ///
+    /// Since overlapping substrings are usually within `max` edits of the pattern too, only the
+    /// best occurrence of each run of overlapping ones is reported: the one ending where the
+    /// distance reaches a local minimum, as late as possible, and starting as late as possible.
+    ///
+    /// Since the empty substring is within `pattern.chars().count()` edits of the pattern, `max`
+    /// is clamped to one edit less than the number of chars in the pattern, and an empty pattern
+    /// has no occurrences.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use string_more::StrExt;
+    ///
+    /// let text = "the colour of the color";
+    /// let occurrences = text.find_approx("colr", 1).collect::<Vec<_>>();
+    /// assert_eq!(occurrences, [(4..8, 1), (18..23, 1)]);
+    /// ```
+    fn find_approx(&self, pattern: &str, max: usize) -> ApproxMatches<'_>;
+
+    /// Computes the sequence of edit operations of minimal length turning `self` into `other`,
+    /// that is a Levenshtein alignment between the strings.
+    /// Each operation spans at most one char and holds the byte ranges it refers to in both strings.
+    fn edit_operations(&self, other: &str) -> Vec<EditOperation>;
+
+    /// Computes the optimal string alignment distance between the strings.
+    /// This is the Levenshtein distance extended with transpositions of adjacent chars,
+    /// under the restriction that no substring is edited more than once.
+    /// The strings may have different lengths.
+    fn osa_distance(&self, other: &str) -> usize;
+
+    /// Computes the optimal string alignment distance between the strings split in the given `unit`.
+    fn osa_distance_by(&self, other: &str, unit: Unit) -> usize;
+
+    /// Computes the optimal string alignment distance between the strings normalized
+    /// according to `options`.
+    fn osa_distance_with(&self, other: &str, options: CompareOptions) -> usize;
+
+    /// Computes the unrestricted Damerau-Levenshtein distance between the strings.
+    /// This is the Levenshtein distance extended with transpositions of adjacent chars.
+    /// The strings may have different lengths.
+    fn damerau_levenshtein_distance(&self, other: &str) -> usize;
+
+    /// Computes the unrestricted Damerau-Levenshtein distance between the strings
+    /// split in the given `unit`.
+    fn damerau_levenshtein_distance_by(&self, other: &str, unit: Unit) -> usize;
+
+    /// Computes the unrestricted Damerau-Levenshtein distance between the strings
+    /// normalized according to `options`.
+    fn damerau_levenshtein_distance_with(&self, other: &str, options: CompareOptions) -> usize;
+
+    /// Computes the minimal total cost of turning `self` into `other` by means of insertions,
+    /// deletions and substitutions of chars, where each operation is priced by `costs`.
+    /// The strings may have different lengths.
+    fn weighted_levenshtein_distance<C: EditCosts + ?Sized>(&self, other: &str, costs: &C) -> f64;
+
+    /// Computes the best local alignment between a region of `self` and a region of `other`
+    /// according to `scores`, as in the Smith-Waterman algorithm with affine gaps.
+    /// The chars surrounding the aligned regions do not affect the score, if no region
+    /// scores above `0.0` the alignment is empty.
+    fn smith_waterman(&self, other: &str, scores: &AlignmentScores) -> Alignment;
+
+    /// Computes the best global alignment between `self` and `other` according to `scores`,
+    /// as in the Needleman-Wunsch algorithm with affine gaps, and returns both strings
+    /// rendered as aligned, with `fill` in place of each char missing from either string.
+    fn needleman_wunsch(
+        &self,
+        other: &str,
+        scores: &AlignmentScores,
+        fill: impl EncodeUtf8,
+    ) -> (String, String);
+
     /// Computes the Hamming distance between the strings.
     /// The strings must have the same lengths, otherwise this
     /// function returns `None`.
     fn hamming_distance(&self, other: &str) -> Option<usize>;
 
+    /// Computes the Hamming distance between the strings split in the given `unit`.
+    /// The strings must have the same lengths in `unit`, otherwise this
+    /// function returns `None`.
+    fn hamming_distance_by(&self, other: &str, unit: Unit) -> Option<usize>;
+
+    /// Computes the Hamming distance between the strings normalized according to `options`.
+    /// The normalized strings must have the same lengths, otherwise this
+    /// function returns `None`.
+    fn hamming_distance_with(&self, other: &str, options: CompareOptions) -> Option<usize>;
+
+    /// Computes the Hamming distance between the strings split in the given `unit`,
+    /// as if the shortest one were padded to the length of the other with units that
+    /// never match, so that each unit of length difference counts as a mismatch.
+    fn padded_hamming_distance(&self, other: &str, unit: Unit) -> usize;
+
+    /// Computes the Jaro similarity between the strings, ranging from `0.0`
+    /// (no similarity at all) to `1.0` (identical strings).
+    /// The strings may have different lengths.
+    ///
+    /// As in Winkler's reference implementation, strcmp95, the number of transpositions is
+    /// half the number of matching chars out of order rounded down, so that an odd number of
+    /// them scores higher than in the textbook formula: `"dcegefff"` and `"egcb"` have a
+    /// similarity of about `0.597` rather than `0.542`.
+    fn jaro_similarity(&self, other: &str) -> f64;
+
+    /// Computes the Jaro similarity between the strings split in the given `unit`.
+    fn jaro_similarity_by(&self, other: &str, unit: Unit) -> f64;
+
+    /// Computes the Jaro similarity between the strings normalized according to `options`.
+    fn jaro_similarity_with(&self, other: &str, options: CompareOptions) -> f64;
+
+    /// Computes the Jaro-Winkler similarity between the strings, ranging from `0.0`
+    /// (no similarity at all) to `1.0` (identical strings).
+    /// The Jaro similarity is boosted by `prefix_scale` for each char of the common prefix
+    /// (up to 4 chars) if it is greater than `boost_threshold`.
+    /// Winkler's original values are `0.1` for `prefix_scale` and `0.7` for `boost_threshold`,
+    /// `prefix_scale` should not exceed `0.25`, otherwise the similarity may exceed `1.0`.
+    fn jaro_winkler_similarity(&self, other: &str, prefix_scale: f64, boost_threshold: f64) -> f64;
+
+    /// Computes the Jaro-Winkler similarity between the strings split in the given `unit`,
+    /// the common prefix being measured in `unit` too.
+    fn jaro_winkler_similarity_by(
+        &self,
+        other: &str,
+        prefix_scale: f64,
+        boost_threshold: f64,
+        unit: Unit,
+    ) -> f64;
+
+    /// Computes the Jaro-Winkler similarity between the strings normalized according to `options`,
+    /// the common prefix being measured on the normalized strings too.
+    fn jaro_winkler_similarity_with(
+        &self,
+        other: &str,
+        prefix_scale: f64,
+        boost_threshold: f64,
+        options: CompareOptions,
+    ) -> f64;
+
+    /// Computes the Jaccard similarity between the sets of q-grams of the strings, ranging
+    /// from `0.0` (no common q-grams) to `1.0` (same q-grams), that is the number of common
+    /// q-grams divided by the number of distinct q-grams of both strings.
+    fn jaccard_similarity(&self, other: &str, qgram: QGram) -> f64;
+
+    /// Computes the Sørensen-Dice similarity between the sets of q-grams of the strings, ranging
+    /// from `0.0` (no common q-grams) to `1.0` (same q-grams), that is twice the number of common
+    /// q-grams divided by the sum of the numbers of distinct q-grams of each string.
+    fn sorensen_dice_similarity(&self, other: &str, qgram: QGram) -> f64;
+
+    /// Computes the cosine similarity between the q-gram frequencies of the strings, ranging
+    /// from `0.0` (no common q-grams) to `1.0` (q-grams in the same proportions).
+    fn cosine_similarity(&self, other: &str, qgram: QGram) -> f64;
+
+    /// Computes the overlap coefficient between the sets of q-grams of the strings, ranging
+    /// from `0.0` (no common q-grams) to `1.0` (the q-grams of one string are all q-grams of the
+    /// other), that is the number of common q-grams divided by the size of the smallest set.
+    fn overlap_coefficient(&self, other: &str, qgram: QGram) -> f64;
+
     /// Computes the frequency of chars in the string.
     /// The user can specify the output map in which the
     /// frequencies will be stored.
-    fn char_frequencies<M: sailed::HzMap>(&self) -> M;
+    fn char_frequencies<M: sailed::HzMap<char>>(&self) -> M;
+
+    /// Returns the frequencies of the q-grams of `self`, the substrings of `qgram.q()`
+    /// consecutive units, padded at the edges according to `qgram`.
+    /// The q-grams of bytes splitting chars are converted lossily, as in `String::from_utf8_lossy`.
+    fn qgram_frequencies<M: sailed::HzMap<String>>(&self, qgram: QGram) -> M;
 
-    /// Returns the longest common substring between `self` and `other`.
+    /// Returns the longest common substring between `self` and `other`, measured in bytes.
+    /// If there are many, the one appearing first in `self` is returned.
+    /// This function runs in linear time in the length of the strings.
     fn longest_common_substring(&self, other: &str) -> &str;
 
+    /// Returns every longest common substring between `self` and `other`,
+    /// that is every distinct common substring of maximal length,
+    /// in order of first appearance in `self`.
+    fn longest_common_substring_all(&self, other: &str) -> Vec<&str>;
+
+    /// Returns the longest substring of `self` that is common to all the strings in `others`.
+    /// If there are many, the one appearing first in `self` is returned.
+    fn longest_common_substring_many(&self, others: &[&str]) -> &str;
+
+    /// Returns the longest common subsequence between `self` and `other`, that is the longest
+    /// sequence of chars appearing in both strings in the same order, but not necessarily contiguously.
+    /// Along with the subsequence, the byte indices of each of its chars in `self` and in `other`
+    /// respectively are returned.
+    fn longest_common_subsequence(&self, other: &str) -> (String, Vec<(usize, usize)>);
+
+    /// Returns the length in chars of the longest common subsequence between `self` and `other`.
+    /// Unlike `longest_common_subsequence` this function only requires linear memory.
+    fn lcs_length(&self, other: &str) -> usize;
+
+    /// Returns the length in `unit` of the longest common subsequence between
+    /// `self` and `other` split in the given `unit`.
+    fn lcs_length_by(&self, other: &str, unit: Unit) -> usize;
+
+    /// Returns the length in chars of the longest common subsequence between `self` and `other`
+    /// normalized according to `options`.
+    fn lcs_length_with(&self, other: &str, options: CompareOptions) -> usize;
+
+    /// Computes the Ratcliff/Obershelp similarity between the strings, ranging from `0.0`
+    /// (no common chars) to `1.0` (identical strings), that is twice the number of chars
+    /// in the matching blocks divided by the total number of chars of both strings.
+    /// This is the `ratio` of Python's `difflib.SequenceMatcher` with no junk heuristic,
+    /// which is its default behaviour for strings shorter than 200 chars.
+    fn ratcliff_obershelp_similarity(&self, other: &str) -> f64;
+
+    /// Returns the blocks of chars common to `self` and `other` found by the Ratcliff/Obershelp
+    /// algorithm, matching the longest common substring and recursing on both its sides.
+    /// As in `difflib`, the blocks are sorted, adjacent blocks are merged and an empty block
+    /// at the end of both strings is appended.
+    fn matching_blocks(&self, other: &str) -> Vec<MatchingBlock>;
+
+    /// Returns the operations turning `self` into `other` according to their matching blocks,
+    /// as the `get_opcodes` of Python's `difflib.SequenceMatcher` does.
+    fn opcodes(&self, other: &str) -> Vec<Opcode>;
+
+    /// Finds the best match of `query` as a subsequence of `self`, scored as in fzf with bonuses
+    /// for matches at word starts, camelCase humps and in runs of consecutive chars.
+    /// Letters match regardless of their case, unless `query` contains uppercase letters.
+    /// Returns `None` if `query` is not a subsequence of `self`.
+    fn fuzzy_match(&self, query: &str) -> Option<FuzzyMatch>;
+
+    /// Finds the best match of `query` as a subsequence of `self`, as `fuzzy_match` does,
+    /// taking the case of letters into account according to `case`.
+    fn fuzzy_match_with(&self, query: &str, case: CaseMatching) -> Option<FuzzyMatch>;
+
+    /// Returns a new `String` with each run of consecutive chars starting at the byte indices
+    /// in `positions` enclosed between `fill_start` and `fill_end`, as in `enclose`.
+    /// The `positions` must be sorted char boundaries, such as those of a `FuzzyMatch`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the positions are not sorted in strictly increasing order,
+    /// or if any of them do not lie on a char boundary, or if it is out of bounds.
+    fn fuzzy_highlight(
+        &self,
+        positions: &[usize],
+        fill_start: impl EncodeUtf8,
+        fill_end: impl EncodeUtf8,
+    ) -> String;
+
+    /// Returns at most `limit` of the `candidates` whose Levenshtein distance from `self`
+    /// does not exceed `max_distance`, closest first, as in "did you mean" suggestions.
+    /// Candidates at the same distance are ranked by their Jaro-Winkler similarity to `self`,
+    /// favouring those sharing a longer prefix, and then by their order in `candidates`.
+    /// When `max_distance` is `None`, one edit is allowed every three chars of `self`,
+    /// rounding down, with at least one edit, as rustc does: a transposition, which costs
+    /// two edits, is then only suggested for queries of at least 6 chars.
+    fn closest_matches<'a>(
+        &self,
+        candidates: impl IntoIterator<Item = &'a str>,
+        max_distance: Option<usize>,
+        limit: usize,
+    ) -> Vec<&'a str>;
+
+    /// Returns the American Soundex code of `self`, made up of its first letter followed by
+    /// three digits, so that names that sound alike share the same code.
+    /// Only ASCII letters are taken into account, the code is empty if there are none:
+    /// the other chars, accented letters included, are silently dropped, so that the code
+    /// of `"Ñuñez"` is `"U200"`, starting with the `U`.
+    fn soundex(&self) -> String;
+
+    /// Returns the Refined Soundex code of `self`, made up of its first letter followed by
+    /// a digit for each group of letters that sound alike, with no length limit.
+    /// Only ASCII letters are taken into account, the code is empty if there are none,
+    /// the other chars, accented letters included, are silently dropped.
+    fn refined_soundex(&self) -> String;
+
+    /// Returns the Metaphone code of `self`, made up of the consonant sounds of the word.
+    /// Only ASCII letters are taken into account, the code is empty if there are none,
+    /// the other chars, accented letters included, are silently dropped.
+    fn metaphone(&self) -> String;
+
+    /// Returns the primary and the alternate Double Metaphone codes of `self`, each made up
+    /// of at most 4 letters, encoding the word only once when both are needed.
+    /// Only ASCII letters, along with `Ç` and `Ñ`, are taken into account, the other chars,
+    /// accented letters included, are silently dropped.
+    fn double_metaphone_codes(&self) -> (String, String);
+
+    /// Returns the primary Double Metaphone code of `self`, made up of at most 4 letters
+    /// standing for the most common pronunciation of the word, see `double_metaphone_codes`.
+    fn double_metaphone(&self) -> String;
+
+    /// Returns the alternate Double Metaphone code of `self`, made up of at most 4 letters
+    /// standing for an alternative pronunciation of the word, often of a foreign origin.
+    /// It is the same as the primary code if there is no alternative pronunciation,
+    /// see `double_metaphone_codes`.
+    fn double_metaphone_alternate(&self) -> String;
+
+    /// Returns the NYSIIS (New York State Identification and Intelligence System)
+    /// code of `self`, made up of at most 6 letters.
+    /// Only ASCII letters are taken into account, the code is empty if there are none,
+    /// the other chars, accented letters included, are silently dropped.
+    fn nysiis(&self) -> String;
+
     /// Get the byte index of the next char in the string starting from index.
     /// If index happens to be on a valid char boundary then index itself is returned.
     /// Note that both 0 and string's length are consedered valid char boundaries.
@@ -201,6 +542,29 @@ pub trait StringExt: StrExt {
 
     /// Replaces all occurrences of a specified substring with another substring in the `String`, modifying it in place.
     fn replace_in_place(&mut self, from: impl EncodeUtf8, to: impl EncodeUtf8);
+
+    /// Applies the edit `operations` computed by `StrExt::edit_operations` between the `String`
+    /// and `target`, turning the `String` into `target` in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ranges of the operations do not lie on char boundaries, or if they are out of bounds.
+    fn apply_edit_operations(&mut self, target: &str, operations: &[EditOperation]);
+
+    /// Replaces the `String` with its American Soundex code, see `StrExt::soundex`.
+    fn soundex_in_place(&mut self);
+
+    /// Replaces the `String` with its Refined Soundex code, see `StrExt::refined_soundex`.
+    fn refined_soundex_in_place(&mut self);
+
+    /// Replaces the `String` with its Metaphone code, see `StrExt::metaphone`.
+    fn metaphone_in_place(&mut self);
+
+    /// Replaces the `String` with its primary Double Metaphone code, see `StrExt::double_metaphone`.
+    fn double_metaphone_in_place(&mut self);
+
+    /// Replaces the `String` with its NYSIIS code, see `StrExt::nysiis`.
+    fn nysiis_in_place(&mut self);
 }
 
 impl<S> StrExt for S
@@ -313,157 +677,641 @@ where
         s
     }
 
-    // Adapted and slightly modified from the code found on
-    // rosettacode: https://rosettacode.org/wiki/Levenshtein_distance#C++
-    // by Martin Ettl; I don't know who you are but thank you! <3
     fn levenshtein_distance(&self, other: &str) -> usize {
-        // --- [this is not part of the adapted code from C++] ---
-        let (source, target) = (self, other);
+        // optimize memory allocations by stripping common
+        // suffix and prefix between source and target.
+        let (source, target) = strip_common_affixes(self, other);
+        let (source_ascii, target_ascii) = (ascii::is_ascii(source), ascii::is_ascii(target));
+        let (source_len, target_len) = (
+            ascii::char_count(source, source_ascii),
+            ascii::char_count(target, target_ascii),
+        );
 
-        // optimize cases where source and target are the same instance.
-        if source.as_ptr() == target.as_ptr() && source.len() == target.len() {
-            return 0;
+        if source_len == 0 || target_len == 0 {
+            return source_len + target_len;
         }
 
-        // optimize memory allocations by stripping common
-        // suffix and prefix between source and target.
+        // the bit-vectors span the chars of the pattern, so we assign the
+        // shortest string to pattern in order to use as few words as possible.
+        let (text, pattern, pattern_len) = if source_len < target_len {
+            (
+                ascii::chars(target, target_ascii),
+                ascii::chars(source, source_ascii),
+                source_len,
+            )
+        } else {
+            (
+                ascii::chars(source, source_ascii),
+                ascii::chars(target, target_ascii),
+                target_len,
+            )
+        };
+
+        if pattern_len <= u64::BITS as usize {
+            myers::distance(text, pattern, pattern_len)
+        } else {
+            myers::blocked_distance(text, pattern, pattern_len)
+        }
+    }
+
+    fn levenshtein_distance_by(&self, other: &str, unit: Unit) -> usize {
+        match unit {
+            Unit::Byte => unit::levenshtein_distance(self.as_bytes(), other.as_bytes()),
+            Unit::Char => self.levenshtein_distance(other),
+            Unit::Grapheme => {
+                unit::levenshtein_distance(&unit::graphemes(self), &unit::graphemes(other))
+            }
+        }
+    }
+
+    fn levenshtein_distance_with(&self, other: &str, options: CompareOptions) -> usize {
+        if options.is_exact() {
+            return self.levenshtein_distance(other);
+        }
+
+        // the normalized chars are counted in a first pass and streamed in a second
+        // one, so that no normalized copy of the strings is ever allocated.
+        let (source_len, target_len) = (options.chars(self).count(), options.chars(other).count());
+
+        if source_len == 0 || target_len == 0 {
+            return source_len + target_len;
+        }
+
+        let (text, pattern, pattern_len) = if source_len < target_len {
+            (options.chars(other), options.chars(self), source_len)
+        } else {
+            (options.chars(self), options.chars(other), target_len)
+        };
+
+        if pattern_len <= u64::BITS as usize {
+            myers::distance(text, pattern, pattern_len)
+        } else {
+            myers::blocked_distance(text, pattern, pattern_len)
+        }
+    }
 
-        let mut end = source
-            .bytes()
-            .rev()
-            .zip(target.bytes().rev())
-            .take_while(|(l, r)| l == r)
-            .count();
+    fn levenshtein_distance_within(&self, other: &str, max: usize) -> Option<usize> {
+        let (source, target) = strip_common_affixes(self, other);
+        let (source_ascii, target_ascii) = (ascii::is_ascii(source), ascii::is_ascii(target));
+        let (source_len, target_len) = (
+            ascii::char_count(source, source_ascii),
+            ascii::char_count(target, target_ascii),
+        );
 
-        // ensure end happens on a valid char boundary
-        while !source.is_char_boundary(source.len() - end) {
-            end -= 1;
+        // the distance is at least the difference in length between the strings.
+        if source_len.abs_diff(target_len) > max {
+            return None;
         }
 
-        // strip common suffix
-        let (source, target) = (&source[..source.len() - end], &target[..target.len() - end]);
+        if source_len == 0 || target_len == 0 {
+            return Some(source_len + target_len);
+        }
 
-        let mut start = source
-            .bytes()
-            .zip(target.bytes())
-            .take_while(|(l, r)| l == r)
-            .count();
+        // the band is indexed by target's chars, so assign the shortest string to target.
+        let (source, target, source_ascii, target_ascii, source_len, target_len) =
+            if source_len < target_len {
+                (
+                    target,
+                    source,
+                    target_ascii,
+                    source_ascii,
+                    target_len,
+                    source_len,
+                )
+            } else {
+                (
+                    source,
+                    target,
+                    source_ascii,
+                    target_ascii,
+                    source_len,
+                    target_len,
+                )
+            };
 
-        // ensure start happens on a valid char boundary
-        while !source.is_char_boundary(start) {
-            start -= 1;
+        if source_ascii && target_ascii {
+            banded_levenshtein_distance(source.bytes(), target.as_bytes(), source_len, max)
+        } else {
+            let mut chars = Vec::with_capacity(target_len);
+            chars.extend(target.chars());
+            banded_levenshtein_distance(ascii::chars(source, source_ascii), &chars, source_len, max)
         }
+    }
+
+    fn levenshtein_automaton(&self, max: usize) -> LevenshteinAutomaton {
+        LevenshteinAutomaton::new(self, max)
+    }
 
-        // strip common prefix
-        let (source, target) = (&source[start..], &target[start..]);
+    fn find_approx(&self, pattern: &str, max: usize) -> ApproxMatches<'_> {
+        ApproxMatches::new(self, pattern, max)
+    }
+
+    fn edit_operations(&self, other: &str) -> Vec<EditOperation> {
+        let (source, target) = strip_common_affixes(self, other);
+        let start = source.as_ptr() as usize - self.as_ptr() as usize;
+        let (source_end, target_end) = (start + source.len(), start + target.len());
+        let mut operations = Vec::with_capacity(usize::max(self.len(), other.len()));
+
+        let keep = |(i, c): (usize, char), source_offset: usize, target_offset: usize| {
+            EditOperation::Keep {
+                source: source_offset + i..source_offset + i + c.len_utf8(),
+                target: target_offset + i..target_offset + i + c.len_utf8(),
+            }
+        };
+
+        operations.extend(self[..start].char_indices().map(|c| keep(c, 0, 0)));
+        edit_operations::edit_operations(source, target, start, start, &mut operations);
+        operations.extend(
+            self[source_end..]
+                .char_indices()
+                .map(|c| keep(c, source_end, target_end)),
+        );
+
+        operations
+    }
 
-        // -- [the adapted code from C++ starts here] ---
+    fn osa_distance(&self, other: &str) -> usize {
+        let (source, target) = strip_common_affixes(self, other);
+        let source = source.chars().collect::<Vec<_>>();
+        let target = target.chars().collect::<Vec<_>>();
+        unit::osa_distance(&source, &target)
+    }
 
-        if source.is_empty() {
-            return target.chars().count();
+    fn osa_distance_by(&self, other: &str, unit: Unit) -> usize {
+        match unit {
+            Unit::Byte => unit::osa_distance(self.as_bytes(), other.as_bytes()),
+            Unit::Char => self.osa_distance(other),
+            Unit::Grapheme => unit::osa_distance(&unit::graphemes(self), &unit::graphemes(other)),
         }
+    }
 
-        if target.is_empty() {
-            return source.chars().count();
+    fn osa_distance_with(&self, other: &str, options: CompareOptions) -> usize {
+        if options.is_exact() {
+            return self.osa_distance(other);
         }
 
-        // --- [this is not part of the adapted code from C++] ---
-        // micro optimization: `costs` vector has the same cardinality
-        // of target's chars so we try to reduce memory allocations by
-        // assigning the smallest string (bytes len) to target.
-        // the memory reduction actually depends on the chars composing
-        // the string, so we optimistically bet that the fewer bytes that
-        // make up the string, the fewer chars will be present in that string.
-        // this is not always true and in those cases we could even end up
-        // allocating more, but those cases should be rare enough to
-        // justify this optimization.
-        let (source, target) = if source.len() < target.len() {
-            (target, source)
+        // rows have the same cardinality of target's chars, so we bet on the shortest one.
+        let (source_len, target_len) = (options.chars(self).count(), options.chars(other).count());
+        let (source, target, target_len) = if source_len < target_len {
+            (options.chars(other), options.chars(self), source_len)
         } else {
-            (source, target)
+            (options.chars(self), options.chars(other), target_len)
         };
-        // --------------------------------------------------------------------
 
-        let target_len = target.chars().count();
-        let mut costs = (0..=target_len).collect::<Vec<_>>();
+        unit::osa_distance_streamed(source, target, target_len)
+    }
 
-        for (source_index, source_char) in source.chars().enumerate() {
-            let mut corner = source_index;
-            costs[0] = source_index + 1;
+    fn damerau_levenshtein_distance(&self, other: &str) -> usize {
+        let (source, target) = strip_common_affixes(self, other);
+        let source = source.chars().collect::<Vec<_>>();
+        let target = target.chars().collect::<Vec<_>>();
+        unit::damerau_levenshtein_distance(&source, &target)
+    }
+
+    fn damerau_levenshtein_distance_by(&self, other: &str, unit: Unit) -> usize {
+        match unit {
+            Unit::Byte => unit::damerau_levenshtein_distance(self.as_bytes(), other.as_bytes()),
+            Unit::Char => self.damerau_levenshtein_distance(other),
+            Unit::Grapheme => {
+                unit::damerau_levenshtein_distance(&unit::graphemes(self), &unit::graphemes(other))
+            }
+        }
+    }
+
+    fn damerau_levenshtein_distance_with(&self, other: &str, options: CompareOptions) -> usize {
+        if options.is_exact() {
+            return self.damerau_levenshtein_distance(other);
+        }
+
+        unit::damerau_levenshtein_distance_streamed(
+            options.chars(self),
+            options.chars(self).count(),
+            options.chars(other),
+            options.chars(other).count(),
+        )
+    }
+
+    fn weighted_levenshtein_distance<C: EditCosts + ?Sized>(&self, other: &str, costs: &C) -> f64 {
+        // NOTE: stripping the common suffix and prefix is not sound here, since with arbitrary
+        // costs it may be cheaper to edit a common char than to keep it (e.g. when a deletion
+        // costs more than a substitution followed by a cheaper deletion elsewhere).
+        let (source, target) = (self, other);
+
+        let mut costs_row = std::iter::once(0.0)
+            .chain(target.chars().scan(0.0, |acc, c| {
+                *acc += costs.insertion(c);
+                Some(*acc)
+            }))
+            .collect::<Vec<_>>();
+
+        for source_char in source.chars() {
+            let mut corner = costs_row[0];
+            costs_row[0] += costs.deletion(source_char);
 
             for (target_index, target_char) in target.chars().enumerate() {
-                let upper = costs[target_index + 1];
+                let upper = costs_row[target_index + 1];
 
-                costs[target_index + 1] = if source_char == target_char {
+                // keeping a common char is free, but not necessarily the cheapest route.
+                let kept = if source_char == target_char {
                     corner
                 } else {
-                    1 + usize::min(usize::min(costs[target_index], upper), corner)
+                    corner + costs.substitution(source_char, target_char)
                 };
 
+                costs_row[target_index + 1] = f64::min(
+                    f64::min(
+                        costs_row[target_index] + costs.insertion(target_char),
+                        upper + costs.deletion(source_char),
+                    ),
+                    kept,
+                );
+
                 corner = upper;
             }
         }
 
-        costs[target_len]
+        costs_row[costs_row.len() - 1]
+    }
+
+    fn smith_waterman(&self, other: &str, scores: &AlignmentScores) -> Alignment {
+        alignment::smith_waterman(self, other, scores)
+    }
+
+    fn needleman_wunsch(
+        &self,
+        other: &str,
+        scores: &AlignmentScores,
+        fill: impl EncodeUtf8,
+    ) -> (String, String) {
+        let mut buf = Default::default();
+        let fill = fill.encode_utf8(&mut buf);
+        let alignment = alignment::needleman_wunsch(self, other, scores);
+        alignment::render(self, other, &alignment.operations, fill)
     }
 
     fn hamming_distance(&self, other: &str) -> Option<usize> {
-        let (mut source, mut target) = (self.chars(), other.chars());
-        let mut distance = 0;
-
-        loop {
-            let source_char = match source.next() {
-                Some(c) => c,
-                None => {
-                    return match target.next() {
-                        Some(_) => None,
-                        None => Some(distance),
-                    }
-                }
-            };
+        self.hamming_distance_by(other, Unit::Char)
+    }
+
+    fn hamming_distance_by(&self, other: &str, unit: Unit) -> Option<usize> {
+        match hamming_mismatches(self, other, unit) {
+            (distance, 0) => Some(distance),
+            _ => None,
+        }
+    }
 
-            let target_char = target.next()?;
-            distance += (source_char != target_char) as usize;
+    fn hamming_distance_with(&self, other: &str, options: CompareOptions) -> Option<usize> {
+        if options.is_exact() {
+            return self.hamming_distance(other);
         }
+
+        match count_mismatches(options.chars(self), options.chars(other)) {
+            (distance, 0) => Some(distance),
+            _ => None,
+        }
+    }
+
+    fn padded_hamming_distance(&self, other: &str, unit: Unit) -> usize {
+        let (distance, unpaired) = hamming_mismatches(self, other, unit);
+        distance + unpaired
+    }
+
+    fn jaro_similarity(&self, other: &str) -> f64 {
+        let source = self.chars().collect::<Vec<_>>();
+        let target = other.chars().collect::<Vec<_>>();
+        unit::jaro_similarity(&source, &target)
+    }
+
+    fn jaro_similarity_by(&self, other: &str, unit: Unit) -> f64 {
+        match unit {
+            Unit::Byte => unit::jaro_similarity(self.as_bytes(), other.as_bytes()),
+            Unit::Char => self.jaro_similarity(other),
+            Unit::Grapheme => {
+                unit::jaro_similarity(&unit::graphemes(self), &unit::graphemes(other))
+            }
+        }
+    }
+
+    fn jaro_similarity_with(&self, other: &str, options: CompareOptions) -> f64 {
+        if options.is_exact() {
+            return self.jaro_similarity(other);
+        }
+
+        unit::jaro_similarity_streamed(
+            options.chars(self),
+            options.chars(self).count(),
+            options.chars(other),
+            options.chars(other).count(),
+        )
+    }
+
+    fn jaro_winkler_similarity(&self, other: &str, prefix_scale: f64, boost_threshold: f64) -> f64 {
+        let source = self.chars().collect::<Vec<_>>();
+        let target = other.chars().collect::<Vec<_>>();
+        unit::jaro_winkler_similarity(&source, &target, prefix_scale, boost_threshold)
     }
 
-    fn char_frequencies<M: sailed::HzMap>(&self) -> M {
-        let mut map = M::default();
-        self.chars().for_each(|c| map.incr(c));
-        map
+    fn jaro_winkler_similarity_by(
+        &self,
+        other: &str,
+        prefix_scale: f64,
+        boost_threshold: f64,
+        unit: Unit,
+    ) -> f64 {
+        match unit {
+            Unit::Byte => unit::jaro_winkler_similarity(
+                self.as_bytes(),
+                other.as_bytes(),
+                prefix_scale,
+                boost_threshold,
+            ),
+            Unit::Char => self.jaro_winkler_similarity(other, prefix_scale, boost_threshold),
+            Unit::Grapheme => unit::jaro_winkler_similarity(
+                &unit::graphemes(self),
+                &unit::graphemes(other),
+                prefix_scale,
+                boost_threshold,
+            ),
+        }
+    }
+
+    fn jaro_winkler_similarity_with(
+        &self,
+        other: &str,
+        prefix_scale: f64,
+        boost_threshold: f64,
+        options: CompareOptions,
+    ) -> f64 {
+        if options.is_exact() {
+            return self.jaro_winkler_similarity(other, prefix_scale, boost_threshold);
+        }
+
+        unit::jaro_winkler_similarity_streamed(
+            options.chars(self),
+            options.chars(self).count(),
+            options.chars(other),
+            options.chars(other).count(),
+            prefix_scale,
+            boost_threshold,
+        )
+    }
+
+    fn jaccard_similarity(&self, other: &str, qgram: QGram) -> f64 {
+        qgram::jaccard_similarity(self, other, qgram)
+    }
+
+    fn sorensen_dice_similarity(&self, other: &str, qgram: QGram) -> f64 {
+        qgram::sorensen_dice_similarity(self, other, qgram)
+    }
+
+    fn cosine_similarity(&self, other: &str, qgram: QGram) -> f64 {
+        qgram::cosine_similarity(self, other, qgram)
+    }
+
+    fn overlap_coefficient(&self, other: &str, qgram: QGram) -> f64 {
+        qgram::overlap_coefficient(self, other, qgram)
+    }
+
+    fn char_frequencies<M: sailed::HzMap<char>>(&self) -> M {
+        qgram::frequencies(self.chars())
+    }
+
+    fn qgram_frequencies<M: sailed::HzMap<String>>(&self, qgram: QGram) -> M {
+        let padded = qgram.pad(self);
+        qgram::frequencies(
+            qgram::qgrams(&padded, qgram).map(|gram| String::from_utf8_lossy(gram).into_owned()),
+        )
     }
 
     fn longest_common_substring(&self, other: &str) -> &str {
-        let (sa, sb) = (self.as_bytes(), other.as_bytes());
-        let mut longest_common_substring = "";
+        longest_common_substrings(self, &[other])
+            .first()
+            .copied()
+            .unwrap_or_default()
+    }
 
-        for ia in 0..sa.len() {
-            if sa.len() - ia < longest_common_substring.len() {
-                break;
+    fn longest_common_substring_all(&self, other: &str) -> Vec<&str> {
+        longest_common_substrings(self, &[other])
+    }
+
+    fn longest_common_substring_many(&self, others: &[&str]) -> &str {
+        longest_common_substrings(self, others)
+            .first()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn longest_common_subsequence(&self, other: &str) -> (String, Vec<(usize, usize)>) {
+        // the common suffix and prefix are always part of a longest common subsequence.
+        let (source, target) = strip_common_affixes(self, other);
+        let start = source.as_ptr() as usize - self.as_ptr() as usize;
+        let (source_end, target_end) = (start + source.len(), start + target.len());
+
+        let source = source
+            .char_indices()
+            .map(|(i, c)| (start + i, c))
+            .collect::<Vec<_>>();
+        let target = target
+            .char_indices()
+            .map(|(i, c)| (start + i, c))
+            .collect::<Vec<_>>();
+
+        // the whole lengths matrix is needed to trace back the subsequence.
+        let columns = target.len() + 1;
+        let mut lengths = vec![0; (source.len() + 1) * columns];
+
+        for i in 1..=source.len() {
+            for j in 1..columns {
+                lengths[i * columns + j] = if source[i - 1].1 == target[j - 1].1 {
+                    lengths[(i - 1) * columns + j - 1] + 1
+                } else {
+                    usize::max(lengths[(i - 1) * columns + j], lengths[i * columns + j - 1])
+                };
             }
+        }
 
-            for ib in 0..sb.len() {
-                if sb.len() - ib < longest_common_substring.len() {
-                    break;
-                }
+        let mut matches = Vec::with_capacity(lengths[lengths.len() - 1]);
+        let (mut i, mut j) = (source.len(), target.len());
 
-                let len = sa[ia..]
-                    .iter()
-                    .zip(sb[ib..].iter())
-                    .take_while(|(ca, cb)| ca == cb)
-                    .count();
-
-                if len > longest_common_substring.len() {
-                    let start = self.next_char_boundary(ia);
-                    let end = self.previous_char_boundary(ia + len);
-                    if end - start > longest_common_substring.len() {
-                        longest_common_substring = &self[start..end];
-                    }
-                }
+        while i > 0 && j > 0 {
+            if source[i - 1].1 == target[j - 1].1 {
+                matches.push((source[i - 1].0, target[j - 1].0));
+                i -= 1;
+                j -= 1;
+            } else if lengths[(i - 1) * columns + j] >= lengths[i * columns + j - 1] {
+                i -= 1;
+            } else {
+                j -= 1;
+            }
+        }
+
+        let prefix = self[..start].char_indices().map(|(i, _)| (i, i));
+        let suffix = self[source_end..]
+            .char_indices()
+            .map(|(i, _)| (source_end + i, target_end + i));
+        let matches = prefix
+            .chain(matches.into_iter().rev())
+            .chain(suffix)
+            .collect::<Vec<_>>();
+
+        let subsequence = matches
+            .iter()
+            .map(|&(i, _)| self[i..].chars().next().unwrap())
+            .collect();
+
+        (subsequence, matches)
+    }
+
+    fn lcs_length(&self, other: &str) -> usize {
+        let (source, target) = strip_common_affixes(self, other);
+        let common = self.chars().count() - source.chars().count();
+        let source = source.chars().collect::<Vec<_>>();
+        let target = target.chars().collect::<Vec<_>>();
+        common + unit::lcs_length(&source, &target)
+    }
+
+    fn lcs_length_by(&self, other: &str, unit: Unit) -> usize {
+        match unit {
+            Unit::Byte => unit::lcs_length(self.as_bytes(), other.as_bytes()),
+            Unit::Char => self.lcs_length(other),
+            Unit::Grapheme => unit::lcs_length(&unit::graphemes(self), &unit::graphemes(other)),
+        }
+    }
+
+    fn lcs_length_with(&self, other: &str, options: CompareOptions) -> usize {
+        if options.is_exact() {
+            return self.lcs_length(other);
+        }
+
+        // the lengths row has the same cardinality of target's chars, so we bet on the
+        // shortest one.
+        let (source_len, target_len) = (options.chars(self).count(), options.chars(other).count());
+        let (source, target, target_len) = if source_len < target_len {
+            (options.chars(other), options.chars(self), source_len)
+        } else {
+            (options.chars(self), options.chars(other), target_len)
+        };
+
+        unit::lcs_length_streamed(source, target, target_len)
+    }
+
+    fn ratcliff_obershelp_similarity(&self, other: &str) -> f64 {
+        let blocks = ratcliff_obershelp::matching_blocks(self, other);
+        ratcliff_obershelp::similarity(self, other, &blocks)
+    }
+
+    fn matching_blocks(&self, other: &str) -> Vec<MatchingBlock> {
+        ratcliff_obershelp::matching_blocks(self, other)
+    }
+
+    fn opcodes(&self, other: &str) -> Vec<Opcode> {
+        ratcliff_obershelp::opcodes(&ratcliff_obershelp::matching_blocks(self, other))
+    }
+
+    fn fuzzy_match(&self, query: &str) -> Option<FuzzyMatch> {
+        self.fuzzy_match_with(query, CaseMatching::Smart)
+    }
+
+    fn fuzzy_match_with(&self, query: &str, case: CaseMatching) -> Option<FuzzyMatch> {
+        fuzzy::fuzzy_match(self, query, case)
+    }
+
+    fn fuzzy_highlight(
+        &self,
+        positions: &[usize],
+        fill_start: impl EncodeUtf8,
+        fill_end: impl EncodeUtf8,
+    ) -> String {
+        let (mut start_buf, mut end_buf) = (Default::default(), Default::default());
+        let (start, end) = (
+            fill_start.encode_utf8(&mut start_buf),
+            fill_end.encode_utf8(&mut end_buf),
+        );
+        let mut string =
+            String::with_capacity(self.len() + (start.len() + end.len()) * positions.len());
+        let mut positions = positions.iter().copied().peekable();
+        let mut index = 0;
+
+        while let Some(run_start) = positions.next() {
+            assert!(
+                index <= run_start && run_start < self.len() && self.is_char_boundary(run_start)
+            );
+
+            // extend the run as long as the following positions are consecutive chars.
+            let mut run_end = self.next_char_boundary(run_start + 1);
+            while run_end < self.len() && positions.next_if_eq(&run_end).is_some() {
+                run_end = self.next_char_boundary(run_end + 1);
             }
+
+            string.push_str(&self[index..run_start]);
+            string.push_str(&(&self[run_start..run_end]).enclose(start, end));
+            index = run_end;
         }
 
-        longest_common_substring
+        string.push_str(&self[index..]);
+        string
+    }
+
+    fn closest_matches<'a>(
+        &self,
+        candidates: impl IntoIterator<Item = &'a str>,
+        max_distance: Option<usize>,
+        limit: usize,
+    ) -> Vec<&'a str> {
+        let max_distance = max_distance.unwrap_or_else(|| usize::max(self.chars().count(), 3) / 3);
+        let mut matches: Vec<_> = candidates
+            .into_iter()
+            .filter_map(|candidate| {
+                let distance = self.levenshtein_distance_within(candidate, max_distance)?;
+                let similarity = self.jaro_winkler_similarity(candidate, 0.1, 0.7);
+                Some((distance, similarity, candidate))
+            })
+            .collect();
+
+        // the sort is stable, so that ties keep the order of the candidates.
+        matches.sort_by(
+            |(l_distance, l_similarity, _), (r_distance, r_similarity, _)| {
+                l_distance.cmp(r_distance).then_with(|| {
+                    r_similarity
+                        .partial_cmp(l_similarity)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+            },
+        );
+
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, candidate)| candidate)
+            .collect()
+    }
+
+    fn soundex(&self) -> String {
+        phonetic::soundex(self)
+    }
+
+    fn refined_soundex(&self) -> String {
+        phonetic::refined_soundex(self)
+    }
+
+    fn metaphone(&self) -> String {
+        phonetic::metaphone(self)
+    }
+
+    fn double_metaphone_codes(&self) -> (String, String) {
+        phonetic::double_metaphone(self)
+    }
+
+    fn double_metaphone(&self) -> String {
+        self.double_metaphone_codes().0
+    }
+
+    fn double_metaphone_alternate(&self) -> String {
+        self.double_metaphone_codes().1
+    }
+
+    fn nysiis(&self) -> String {
+        phonetic::nysiis(self)
     }
 
     fn next_char_boundary(&self, mut index: usize) -> usize {
@@ -503,39 +1351,208 @@ where
     }
 }
 
-impl StringExt for String {
-    fn set(&mut self, s: &str) {
-        self.clear();
-        self.push_str(s);
+/// Strips the common suffix and prefix between `source` and `target`,
+/// taking care of cutting both strings on valid char boundaries.
+fn strip_common_affixes<'a>(source: &'a str, target: &'a str) -> (&'a str, &'a str) {
+    // optimize cases where source and target are the same instance.
+    if source.as_ptr() == target.as_ptr() && source.len() == target.len() {
+        return (&source[..0], &target[..0]);
     }
 
-    fn trim_start_in_place(&mut self) {
-        let trimmed = self.trim_start();
-        let start = unsafe { trimmed.as_ptr().offset_from(self.as_ptr()) as usize };
-        let len = trimmed.len();
-        unsafe { self.as_mut_vec().copy_within(start..start + len, 0) };
-        self.truncate(len);
-    }
+    let mut end = source
+        .bytes()
+        .rev()
+        .zip(target.bytes().rev())
+        .take_while(|(l, r)| l == r)
+        .count();
 
-    fn trim_end_in_place(&mut self) {
-        self.truncate(self.trim_end().len());
+    // ensure end happens on a valid char boundary
+    while !source.is_char_boundary(source.len() - end) {
+        end -= 1;
     }
 
-    fn trim_in_place(&mut self) {
-        let trimmed = self.trim();
-        let start = unsafe { trimmed.as_ptr().offset_from(self.as_ptr()) } as usize;
-        let len = trimmed.len();
-        unsafe { self.as_mut_vec().copy_within(start..start + len, 0) };
-        self.truncate(len);
-    }
+    // strip common suffix
+    let (source, target) = (&source[..source.len() - end], &target[..target.len() - end]);
 
-    fn fill_start_in_place(&mut self, fill: impl EncodeUtf8, times: usize) {
-        let mut buf = Default::default();
-        let fill = fill.encode_utf8(&mut buf);
+    let mut start = source
+        .bytes()
+        .zip(target.bytes())
+        .take_while(|(l, r)| l == r)
+        .count();
 
-        if fill.is_empty() || times == 0 {
-            return;
-        }
+    // ensure start happens on a valid char boundary
+    while !source.is_char_boundary(start) {
+        start -= 1;
+    }
+
+    // strip common prefix
+    (&source[start..], &target[start..])
+}
+
+// Ukkonen's cut-off: only the diagonal band of width `2 * max + 1` of the
+// distance matrix can hold values within `max`, so only that band is computed.
+fn banded_levenshtein_distance<T: PartialEq>(
+    source: impl Iterator<Item = T>,
+    target: &[T],
+    source_len: usize,
+    max: usize,
+) -> Option<usize> {
+    let target_len = target.len();
+
+    // the distance never exceeds the length of the longest string, clamping `max`
+    // keeps `infinity` from overflowing.
+    let max = max.min(source_len);
+    let infinity = max + 1;
+    let mut costs = (0..=target_len)
+        .map(|j| if j <= max { j } else { infinity })
+        .collect::<Vec<_>>();
+
+    for (source_index, source_char) in source.enumerate() {
+        let row = source_index + 1;
+        let start = row.saturating_sub(max).max(1);
+        let end = usize::min(target_len, row + max);
+
+        let mut corner = costs[start - 1];
+        costs[start - 1] = if start == 1 && row <= max {
+            row
+        } else {
+            infinity
+        };
+        let mut row_min = costs[start - 1];
+
+        for target_index in start..=end {
+            let upper = costs[target_index];
+
+            let cost = if source_char == target[target_index - 1] {
+                corner
+            } else {
+                1 + usize::min(usize::min(costs[target_index - 1], upper), corner)
+            };
+
+            costs[target_index] = cost.min(infinity);
+            row_min = row_min.min(costs[target_index]);
+            corner = upper;
+        }
+
+        // every path to the last cell crosses this row, give up early.
+        if row_min > max {
+            return None;
+        }
+    }
+
+    Some(costs[target_len]).filter(|&distance| distance <= max)
+}
+
+/// Returns every distinct substring of `text` of maximal length (in bytes) that is common
+/// to all the strings in `others`, in order of first appearance in `text`.
+fn longest_common_substrings<'a>(text: &'a str, others: &[&str]) -> Vec<&'a str> {
+    let (other, others) = match others.split_first() {
+        Some(split) => split,
+        // with no other strings, the whole text is the longest common substring.
+        None if text.is_empty() => return Vec::new(),
+        None => return vec![text],
+    };
+
+    let automaton = SuffixAutomaton::new(text);
+    let lengths = others
+        .iter()
+        .fold(automaton.match_lengths(other), |mut lengths, other| {
+            for (length, other) in lengths.iter_mut().zip(automaton.match_lengths(other)) {
+                *length = usize::min(*length, other);
+            }
+            lengths
+        });
+
+    let mut longest = Vec::<&str>::new();
+    for substring in automaton.substrings(&lengths) {
+        let len = longest.first().map_or(0, |s| s.len());
+
+        if substring.len() > len {
+            longest.clear();
+        }
+
+        if substring.len() >= len {
+            longest.push(substring);
+        }
+    }
+
+    longest.sort_by_key(|s| s.as_ptr());
+    longest
+}
+
+/// Splits `source` and `target` in `unit`, returning the number of mismatching units at the
+/// same positions and the number of units of the longest string exceeding the shortest one.
+fn hamming_mismatches(source: &str, target: &str, unit: Unit) -> (usize, usize) {
+    // compares the bytes pairwise with no UTF-8 decoding at all.
+    fn count_bytes(source: &[u8], target: &[u8]) -> (usize, usize) {
+        let distance = source.iter().zip(target).filter(|(l, r)| l != r).count();
+        (distance, source.len().abs_diff(target.len()))
+    }
+
+    match unit {
+        Unit::Byte => count_bytes(source.as_bytes(), target.as_bytes()),
+        // the chars of ASCII strings are their bytes.
+        Unit::Char if ascii::is_ascii(source) && ascii::is_ascii(target) => {
+            count_bytes(source.as_bytes(), target.as_bytes())
+        }
+        Unit::Char => count_mismatches(source.chars(), target.chars()),
+        Unit::Grapheme => {
+            count_mismatches(grapheme::graphemes(source), grapheme::graphemes(target))
+        }
+    }
+}
+
+/// Returns the number of mismatching items of `source` and `target` at the same positions
+/// and the number of items of the longest sequence exceeding the shortest one.
+fn count_mismatches<T: PartialEq>(
+    mut source: impl Iterator<Item = T>,
+    mut target: impl Iterator<Item = T>,
+) -> (usize, usize) {
+    let mut distance = 0;
+
+    loop {
+        match (source.next(), target.next()) {
+            (Some(l), Some(r)) => distance += (l != r) as usize,
+            (Some(_), None) => return (distance, 1 + source.count()),
+            (None, Some(_)) => return (distance, 1 + target.count()),
+            (None, None) => return (distance, 0),
+        }
+    }
+}
+
+impl StringExt for String {
+    fn set(&mut self, s: &str) {
+        self.clear();
+        self.push_str(s);
+    }
+
+    fn trim_start_in_place(&mut self) {
+        let trimmed = self.trim_start();
+        let start = unsafe { trimmed.as_ptr().offset_from(self.as_ptr()) as usize };
+        let len = trimmed.len();
+        unsafe { self.as_mut_vec().copy_within(start..start + len, 0) };
+        self.truncate(len);
+    }
+
+    fn trim_end_in_place(&mut self) {
+        self.truncate(self.trim_end().len());
+    }
+
+    fn trim_in_place(&mut self) {
+        let trimmed = self.trim();
+        let start = unsafe { trimmed.as_ptr().offset_from(self.as_ptr()) } as usize;
+        let len = trimmed.len();
+        unsafe { self.as_mut_vec().copy_within(start..start + len, 0) };
+        self.truncate(len);
+    }
+
+    fn fill_start_in_place(&mut self, fill: impl EncodeUtf8, times: usize) {
+        let mut buf = Default::default();
+        let fill = fill.encode_utf8(&mut buf);
+
+        if fill.is_empty() || times == 0 {
+            return;
+        }
 
         #[allow(clippy::uninit_vec)]
         unsafe {
@@ -687,6 +1704,50 @@ impl StringExt for String {
             offset += to.len();
         }
     }
+
+    fn apply_edit_operations(&mut self, target: &str, operations: &[EditOperation]) {
+        // applying the operations from the last one keeps the
+        // source ranges of the preceding ones valid.
+        for operation in operations.iter().rev() {
+            match operation {
+                EditOperation::Keep { .. } => {}
+                EditOperation::Insert { source, target: t } => {
+                    self.insert_str(source.start, &target[t.clone()])
+                }
+                EditOperation::Delete { source, .. } => {
+                    self.replace_range(source.clone(), "");
+                }
+                EditOperation::Substitute { source, target: t } => {
+                    self.replace_range(source.clone(), &target[t.clone()]);
+                }
+            }
+        }
+    }
+
+    fn soundex_in_place(&mut self) {
+        let code = phonetic::soundex(self);
+        self.set(&code);
+    }
+
+    fn refined_soundex_in_place(&mut self) {
+        let code = phonetic::refined_soundex(self);
+        self.set(&code);
+    }
+
+    fn metaphone_in_place(&mut self) {
+        let code = phonetic::metaphone(self);
+        self.set(&code);
+    }
+
+    fn double_metaphone_in_place(&mut self) {
+        let (code, _) = phonetic::double_metaphone(self);
+        self.set(&code);
+    }
+
+    fn nysiis_in_place(&mut self) {
+        let code = phonetic::nysiis(self);
+        self.set(&code);
+    }
 }
 
 impl EncodeUtf8 for char {
@@ -715,9 +1776,15 @@ impl EncodeUtf8 for String {
 
 #[cfg(test)]
 mod tests {
+    use std::cmp::Reverse;
     use std::collections::{BTreeMap, HashMap};
+    use std::ops::Range;
 
-    use super::{EncodeUtf8, StrExt, StringExt};
+    use super::{
+        AlignmentScores, CaseInsensitiveCosts, CaseMatching, CompareOptions, EditCosts,
+        EditOperation, EncodeUtf8, KeyboardCosts, MatchingBlock, Opcode, QGram, StrExt, StringExt,
+        UniformCosts, Unit,
+    };
 
     #[test]
     fn encode_utf8() {
@@ -1298,6 +2365,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn apply_edit_operations() {
+        const SEED: [(&str, &str); 12] = [
+            ("", ""),
+            ("", "a"),
+            ("a", ""),
+            ("abc", "def"),
+            ("ring", "bring"),
+            ("string", "ring"),
+            ("kitten", "sitting"),
+            ("rosettacode", "raisethysword"),
+            ("Āgain", "āgain"),
+            ("cafexĀ", "cafeyȀ"),
+            ("·x·", "x··y"),
+            ("lorem ipsum dolor", "ipsum"),
+        ];
+
+        for (init, target) in SEED {
+            let mut sut = init.to_string();
+            sut.apply_edit_operations(target, &init.edit_operations(target));
+            assert_eq!(sut, target, "init: \"{init}\" target: \"{target}\"");
+        }
+    }
+
+    #[test]
+    fn phonetic_in_place() {
+        const SEED: [&str; 5] = ["", "Smith", "Schmidt", "Gallegos", "Macintosh"];
+
+        for init in SEED {
+            let mut sut = init.to_string();
+            sut.soundex_in_place();
+            assert_eq!(sut, init.soundex());
+
+            let mut sut = init.to_string();
+            sut.refined_soundex_in_place();
+            assert_eq!(sut, init.refined_soundex());
+
+            let mut sut = init.to_string();
+            sut.metaphone_in_place();
+            assert_eq!(sut, init.metaphone());
+
+            let mut sut = init.to_string();
+            sut.double_metaphone_in_place();
+            assert_eq!(sut, init.double_metaphone());
+
+            let mut sut = init.to_string();
+            sut.nysiis_in_place();
+            assert_eq!(sut, init.nysiis());
+        }
+    }
+
     #[test]
     fn levenshtein_distance() {
         const SEED: [(&str, &str, usize); 18] = [
@@ -1326,6 +2444,776 @@ mod tests {
         }
     }
 
+    // Adapted and slightly modified from the code found on
+    // rosettacode: https://rosettacode.org/wiki/Levenshtein_distance#C++
+    // by Martin Ettl; I don't know who you are but thank you! <3
+    fn reference_levenshtein_distance(source: &str, target: &str) -> usize {
+        let target_len = target.chars().count();
+        let mut costs = (0..=target_len).collect::<Vec<_>>();
+
+        for (source_index, source_char) in source.chars().enumerate() {
+            let mut corner = source_index;
+            costs[0] = source_index + 1;
+
+            for (target_index, target_char) in target.chars().enumerate() {
+                let upper = costs[target_index + 1];
+
+                costs[target_index + 1] = if source_char == target_char {
+                    corner
+                } else {
+                    1 + usize::min(usize::min(costs[target_index], upper), corner)
+                };
+
+                corner = upper;
+            }
+        }
+
+        costs[target_len]
+    }
+
+    // deterministic pseudo-random strings over a small alphabet, so that
+    // the generated strings share plenty of chars.
+    fn random_strings(count: usize, max_len: usize) -> Vec<String> {
+        const ALPHABET: [char; 6] = ['a', 'b', 'c', 'd', '·', 'Ā'];
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as usize
+        };
+
+        (0..count)
+            .map(|_| {
+                let len = next() % (max_len + 1);
+                (0..len)
+                    .map(|_| ALPHABET[next() % ALPHABET.len()])
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn levenshtein_distance_bit_parallel() {
+        let strings = random_strings(40, 160);
+
+        for sut in &strings {
+            for other in &strings {
+                assert_eq!(
+                    sut.levenshtein_distance(other),
+                    reference_levenshtein_distance(sut, other),
+                    "{sut} {other}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ascii_fast_paths() {
+        // every ASCII string is paired with its transliteration into non-ASCII chars,
+        // which must be just as distant from the others as the ASCII string is.
+        let to_ascii = |c| match c {
+            '·' => 'e',
+            'Ā' => 'f',
+            c => c,
+        };
+        let to_unicode = |s: &str| -> String {
+            s.chars()
+                .map(|c| char::from_u32(c as u32 + 0x100).unwrap())
+                .collect()
+        };
+
+        let strings = random_strings(20, 160)
+            .iter()
+            .map(|s| s.chars().map(to_ascii).collect::<String>())
+            .map(|s| {
+                let unicode = to_unicode(&s);
+                (s, unicode)
+            })
+            .collect::<Vec<_>>();
+
+        for (sut, sut_unicode) in &strings {
+            for (other, other_unicode) in &strings {
+                let distance = sut.levenshtein_distance(other);
+                assert_eq!(distance, reference_levenshtein_distance(sut, other));
+                assert_eq!(distance, sut_unicode.levenshtein_distance(other_unicode));
+
+                for max in [0, 1, distance.saturating_sub(1), distance, distance + 1] {
+                    assert_eq!(
+                        sut.levenshtein_distance_within(other, max),
+                        sut_unicode.levenshtein_distance_within(other_unicode, max),
+                    );
+                }
+
+                assert_eq!(
+                    sut.hamming_distance(other),
+                    sut_unicode.hamming_distance(other_unicode)
+                );
+                assert_eq!(
+                    to_unicode(sut.longest_common_substring(other)),
+                    sut_unicode.longest_common_substring(other_unicode)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn levenshtein_distance_within() {
+        const SEED: [(&str, &str, usize, Option<usize>); 22] = [
+            ("", "", 0, Some(0)),
+            ("", "a", 0, None),
+            ("", "a", 1, Some(1)),
+            ("a", "", 1, Some(1)),
+            ("abc", "def", 2, None),
+            ("abc", "def", 3, Some(3)),
+            ("ring", "bring", 1, Some(1)),
+            ("string", "ring", 1, None),
+            ("kitten", "sitting", 2, None),
+            ("kitten", "sitting", 3, Some(3)),
+            ("kitten", "sitting", usize::MAX, Some(3)),
+            ("saturday", "sunday", 3, Some(3)),
+            ("execution", "intention", 4, None),
+            ("execution", "intention", 5, Some(5)),
+            ("rosettacode", "raisethysword", 8, Some(8)),
+            ("rosettacode", "raisethysword", 7, None),
+            ("rosettacode", "rosettacode", 0, Some(0)),
+            ("Āgain", "āgain", 1, Some(1)),
+            ("cafexĀ", "cafeyȀ", 1, None),
+            ("Āxcafe", "Ȁycafe", 2, Some(2)),
+            ("lorem ipsum dolor", "ipsum", 11, None),
+            ("ipsum", "lorem ipsum dolor", 12, Some(12)),
+        ];
+
+        for (sut, other, max, expected) in SEED {
+            assert_eq!(
+                sut.levenshtein_distance_within(other, max),
+                expected,
+                "{sut} {other} {max}"
+            );
+        }
+    }
+
+    #[test]
+    fn levenshtein_automaton() {
+        let queries = random_strings(15, 8);
+        let mut words = random_strings(300, 10);
+        words.sort_unstable();
+
+        for query in &queries {
+            for max in 0..4 {
+                let automaton = query.levenshtein_automaton(max);
+                let expected = words
+                    .iter()
+                    .map(|word| (word.as_str(), query.levenshtein_distance(word)))
+                    .filter(|&(_, distance)| distance <= max)
+                    .collect::<Vec<_>>();
+
+                assert_eq!(automaton.search(words.iter().map(String::as_str)), expected);
+
+                for word in &words {
+                    assert_eq!(
+                        automaton.distance(word),
+                        query.levenshtein_distance_within(word, max),
+                        "{query} {word} {max}"
+                    );
+                }
+            }
+        }
+
+        // unsorted words are searched too, with no prefix shared.
+        let automaton = "kitten".levenshtein_automaton(3);
+        assert_eq!(
+            automaton.search(["sitting", "kitchen", "mitten", "kit", "smitten"]),
+            [
+                ("sitting", 3),
+                ("kitchen", 2),
+                ("mitten", 1),
+                ("kit", 3),
+                ("smitten", 2)
+            ]
+        );
+        assert_eq!(automaton.max(), 3);
+        assert_eq!("".levenshtein_automaton(0).search(["", "a"]), [("", 0)]);
+    }
+
+    #[test]
+    fn find_approx() {
+        let patterns = random_strings(20, 6);
+        let texts = random_strings(20, 24);
+
+        for text in &texts {
+            let boundaries = text
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([text.len()])
+                .collect::<Vec<_>>();
+
+            for pattern in &patterns {
+                // the least distance of the substrings ending at each char, and their latest start.
+                let ends = boundaries[1..]
+                    .iter()
+                    .map(|&end| {
+                        let (distance, start) = boundaries
+                            .iter()
+                            .take_while(|&&start| start <= end)
+                            .map(|&start| {
+                                ((&text[start..end]).levenshtein_distance(pattern), start)
+                            })
+                            .min_by_key(|&(distance, start)| (distance, Reverse(start)))
+                            .unwrap();
+                        (start..end, distance)
+                    })
+                    .collect::<Vec<_>>();
+
+                for max in 0..4 {
+                    let max = max.min(pattern.chars().count().saturating_sub(1));
+                    let mut expected = Vec::new();
+
+                    for (index, (range, distance)) in ends.iter().enumerate() {
+                        // the first end of the non-increasing run of distances within `max`.
+                        let first = index
+                            - ends[..index]
+                                .iter()
+                                .rev()
+                                .zip(ends[..=index].iter().rev())
+                                .take_while(|((_, previous), (_, next))| {
+                                    previous >= next && *previous <= max
+                                })
+                                .count();
+
+                        // the run decreases, rather than continuing a plateau reported before.
+                        let decreases =
+                            first == 0 || ends[first - 1].1 > max || ends[first].1 > *distance;
+
+                        if !pattern.is_empty()
+                            && *distance <= max
+                            && ends
+                                .get(index + 1)
+                                .map_or(true, |(_, next)| next > distance)
+                            && decreases
+                        {
+                            expected.push((range.clone(), *distance));
+                        }
+                    }
+
+                    let occurrences = text.find_approx(pattern, max).collect::<Vec<_>>();
+                    assert_eq!(occurrences, expected, "{text} {pattern} {max}");
+                }
+            }
+        }
+
+        assert_eq!(
+            "abab".find_approx("ab", 1).collect::<Vec<_>>(),
+            [(0..2, 0), (2..4, 0)]
+        );
+        assert_eq!(
+            "a·cd ab·d".find_approx("abcd", 1).collect::<Vec<_>>(),
+            [(0..5, 1), (6..11, 1)]
+        );
+        assert_eq!("abc".find_approx("abc", 5).collect::<Vec<_>>(), [(0..3, 0)]);
+        assert_eq!("abc".find_approx("", 2).next(), None);
+        assert_eq!("".find_approx("abc", 2).next(), None);
+
+        // longer patterns than a machine word.
+        let pattern = "abcdefghijklmnopqrstuvwxyz".repeat(3)[..70].to_string();
+        let text = format!("xx{}yy{}", pattern.replacen('c', "·", 2), pattern);
+        assert_eq!(
+            text.find_approx(&pattern, 2).collect::<Vec<_>>(),
+            [(2..74, 2), (76..146, 0)]
+        );
+    }
+
+    #[test]
+    fn edit_operations() {
+        const SEED: [(&str, &str); 14] = [
+            ("", ""),
+            ("", "a"),
+            ("a", ""),
+            ("abc", "def"),
+            ("ring", "bring"),
+            ("string", "ring"),
+            ("update", "udpate"),
+            ("kitten", "sitting"),
+            ("saturday", "sunday"),
+            ("rosettacode", "raisethysword"),
+            ("rosettacode", "rosettacode"),
+            ("Āgain", "āgain"),
+            ("cafexĀ", "cafeyȀ"),
+            ("lorem ipsum dolor", "ipsum"),
+        ];
+
+        for (sut, other) in SEED {
+            let operations = sut.edit_operations(other);
+            assert_eq!(
+                operations.iter().filter(|o| o.is_edit()).count(),
+                sut.levenshtein_distance(other),
+                "{sut} {other}"
+            );
+
+            // the operations span both strings contiguously.
+            let (mut source, mut target) = (0, 0);
+            for operation in &operations {
+                assert_eq!(operation.source().start, source, "{sut} {other}");
+                assert_eq!(operation.target().start, target, "{sut} {other}");
+                source = operation.source().end;
+                target = operation.target().end;
+            }
+            assert_eq!((source, target), (sut.len(), other.len()), "{sut} {other}");
+        }
+
+        assert_eq!(
+            "kitten".edit_operations("sitting"),
+            [
+                EditOperation::Substitute {
+                    source: 0..1,
+                    target: 0..1
+                },
+                EditOperation::Keep {
+                    source: 1..2,
+                    target: 1..2
+                },
+                EditOperation::Keep {
+                    source: 2..3,
+                    target: 2..3
+                },
+                EditOperation::Keep {
+                    source: 3..4,
+                    target: 3..4
+                },
+                EditOperation::Substitute {
+                    source: 4..5,
+                    target: 4..5
+                },
+                EditOperation::Keep {
+                    source: 5..6,
+                    target: 5..6
+                },
+                EditOperation::Insert {
+                    source: 6..6,
+                    target: 6..7
+                },
+            ]
+        );
+
+        assert_eq!(
+            "xĀy".edit_operations("xy"),
+            [
+                EditOperation::Keep {
+                    source: 0..1,
+                    target: 0..1
+                },
+                EditOperation::Delete {
+                    source: 1..3,
+                    target: 1..1
+                },
+                EditOperation::Keep {
+                    source: 3..4,
+                    target: 1..2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn osa_distance() {
+        const SEED: [(&str, &str, usize); 20] = [
+            ("", "", 0),
+            ("", "a", 1),
+            ("a", "", 1),
+            ("ab", "ba", 1),
+            ("teh", "the", 1),
+            ("abc", "def", 3),
+            ("ring", "bring", 1),
+            ("update", "udpate", 1),
+            ("kitten", "sitting", 3),
+            ("saturday", "sunday", 3),
+            ("ca", "abc", 3),
+            ("abcdef", "badcfe", 3),
+            ("rosettacode", "rosettacode", 0),
+            ("Āgain", "āgain", 1),
+            ("Āā", "āĀ", 1),
+            ("agĀāin", "agāĀin", 1),
+            ("cafexĀ", "cafeĀx", 1),
+            ("Āxcafe", "xĀcafe", 1),
+            ("lorem ipsum dolor", "ipsum", 12),
+            ("ipsum", "lorem ipsum dolor", 12),
+        ];
+
+        for (sut, other, expected) in SEED {
+            assert_eq!(sut.osa_distance(other), expected, "{sut} {other}");
+        }
+    }
+
+    #[test]
+    fn damerau_levenshtein_distance() {
+        const SEED: [(&str, &str, usize); 20] = [
+            ("", "", 0),
+            ("", "a", 1),
+            ("a", "", 1),
+            ("ab", "ba", 1),
+            ("teh", "the", 1),
+            ("abc", "def", 3),
+            ("ring", "bring", 1),
+            ("update", "udpate", 1),
+            ("kitten", "sitting", 3),
+            ("saturday", "sunday", 3),
+            ("ca", "abc", 2),
+            ("abcdef", "badcfe", 3),
+            ("rosettacode", "rosettacode", 0),
+            ("Āgain", "āgain", 1),
+            ("Āā", "āĀ", 1),
+            ("agĀāin", "agāĀin", 1),
+            ("cafexĀ", "cafeĀx", 1),
+            ("Āxcafe", "xĀcafe", 1),
+            ("lorem ipsum dolor", "ipsum", 12),
+            ("ipsum", "lorem ipsum dolor", 12),
+        ];
+
+        for (sut, other, expected) in SEED {
+            assert_eq!(
+                sut.damerau_levenshtein_distance(other),
+                expected,
+                "{sut} {other}"
+            );
+        }
+    }
+
+    #[test]
+    fn weighted_levenshtein_distance() {
+        const SEED: [(&str, &str, f64, f64, f64); 14] = [
+            ("", "", 0.0, 0.0, 0.0),
+            ("", "a", 1.0, 1.0, 1.0),
+            ("a", "", 1.0, 1.0, 1.0),
+            ("kitten", "sitting", 3.0, 3.0, 3.0),
+            ("Kitten", "kitten", 1.0, 0.0, 1.0),
+            ("HELLO", "hello", 5.0, 0.0, 5.0),
+            ("hello", "hrllo", 1.0, 1.0, 0.5),
+            ("hello", "jello", 1.0, 1.0, 0.5),
+            ("Hello", "jello", 1.0, 1.0, 0.5),
+            ("hello", "hemmo", 2.0, 2.0, 2.0),
+            ("quiz", "wuix", 2.0, 2.0, 1.0),
+            ("1", "q", 1.0, 1.0, 0.5),
+            ("Āgain", "āgain", 1.0, 0.0, 1.0),
+            ("cafexĀ", "cafeyȀ", 2.0, 2.0, 2.0),
+        ];
+
+        for (sut, other, uniform, case_insensitive, keyboard) in SEED {
+            assert_eq!(
+                sut.weighted_levenshtein_distance(other, &UniformCosts),
+                uniform,
+                "{sut} {other}"
+            );
+            assert_eq!(
+                sut.weighted_levenshtein_distance(other, &CaseInsensitiveCosts::default()),
+                case_insensitive,
+                "{sut} {other}"
+            );
+            assert_eq!(
+                sut.weighted_levenshtein_distance(other, &KeyboardCosts::default()),
+                keyboard,
+                "{sut} {other}"
+            );
+        }
+
+        let costs = CaseInsensitiveCosts {
+            case_substitution: 0.25,
+        };
+        assert_eq!("HeLLo".weighted_levenshtein_distance("hello", &costs), 0.75);
+
+        // keeping the common `a` would force the costly insertion of `x`.
+        struct CostlyX;
+
+        impl EditCosts for CostlyX {
+            fn insertion(&self, c: char) -> f64 {
+                if c == 'x' {
+                    10.0
+                } else {
+                    1.0
+                }
+            }
+
+            fn deletion(&self, _: char) -> f64 {
+                1.0
+            }
+
+            fn substitution(&self, _: char, _: char) -> f64 {
+                1.0
+            }
+        }
+
+        assert_eq!("a".weighted_levenshtein_distance("xa", &CostlyX), 2.0);
+        assert_eq!("ab".weighted_levenshtein_distance("axb", &CostlyX), 2.0);
+    }
+
+    #[test]
+    fn jaro_similarity() {
+        const SEED: [(&str, &str, f64); 15] = [
+            ("", "", 1.0),
+            ("", "a", 0.0),
+            ("a", "", 0.0),
+            ("a", "a", 1.0),
+            ("abc", "def", 0.0),
+            ("martha", "marhta", 0.944),
+            ("dixon", "dicksonx", 0.767),
+            ("jellyfish", "smellyfish", 0.896),
+            ("dwayne", "duane", 0.822),
+            ("crate", "trace", 0.733),
+            ("Āgain", "āgain", 0.867),
+            ("cafexĀ", "cafeĀx", 0.944),
+            ("rosettacode", "rosettacode", 1.0),
+            ("ab", "ba", 0.0),
+            // 3 chars out of order count as 1 transposition, not 1.5.
+            ("dcegefff", "egcb", 0.597),
+        ];
+
+        for (sut, other, expected) in SEED {
+            let similarity = sut.jaro_similarity(other);
+            assert!(
+                (similarity - expected).abs() < 1e-3,
+                "{sut} {other} {similarity} {expected}"
+            );
+            assert_eq!(similarity, other.jaro_similarity(sut));
+        }
+    }
+
+    #[test]
+    fn jaro_winkler_similarity() {
+        const SEED: [(&str, &str, f64, f64, f64); 12] = [
+            ("", "", 0.1, 0.7, 1.0),
+            ("", "a", 0.1, 0.7, 0.0),
+            ("abc", "def", 0.1, 0.7, 0.0),
+            ("martha", "marhta", 0.1, 0.7, 0.961),
+            ("martha", "marhta", 0.2, 0.7, 0.978),
+            ("martha", "marhta", 0.1, 0.95, 0.944),
+            ("dixon", "dicksonx", 0.1, 0.7, 0.813),
+            ("jellyfish", "smellyfish", 0.1, 0.7, 0.896),
+            ("dwayne", "duane", 0.1, 0.7, 0.840),
+            ("crate", "trace", 0.1, 0.7, 0.733),
+            ("cafexĀ", "cafeĀx", 0.1, 0.7, 0.967),
+            ("rosettacode", "rosettacode", 0.25, 0.0, 1.0),
+        ];
+
+        for (sut, other, prefix_scale, boost_threshold, expected) in SEED {
+            let similarity = sut.jaro_winkler_similarity(other, prefix_scale, boost_threshold);
+            assert!(
+                (similarity - expected).abs() < 1e-3,
+                "{sut} {other} {similarity} {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn smith_waterman() {
+        type Expected = (f64, Range<usize>, Range<usize>);
+
+        const SEED: [(&str, &str, Expected); 8] = [
+            ("", "abc", (0.0, 0..0, 0..0)),
+            ("abc", "", (0.0, 0..0, 0..0)),
+            ("abc", "xyz", (0.0, 0..0, 0..0)),
+            ("xxabcxx", "abc", (6.0, 2..5, 0..3)),
+            ("the quick brown fox", "quack", (7.0, 4..9, 0..5)),
+            ("abcdef", "abdef", (8.0, 0..6, 0..5)),
+            ("abcxxxxdef", "abcdef", (7.0, 0..10, 0..6)),
+            ("·ab·", "xab", (4.0, 2..4, 1..3)),
+        ];
+
+        let scores = AlignmentScores::default();
+        for (sut, other, (score, source, target)) in SEED {
+            let alignment = sut.smith_waterman(other, &scores);
+            assert_eq!(alignment.score, score, "{sut} {other}");
+            assert_eq!(alignment.source, source, "{sut} {other}");
+            assert_eq!(alignment.target, target, "{sut} {other}");
+
+            let mut aligned = sut[source].to_string();
+            for operation in alignment.operations.iter().rev() {
+                let (s, t) = (operation.source(), operation.target());
+                let s = s.start - alignment.source.start..s.end - alignment.source.start;
+                aligned.replace_range(s, &other[t]);
+            }
+            assert_eq!(aligned, other[target], "{sut} {other}");
+        }
+
+        let alignment = "abcdef".smith_waterman("abdef", &scores);
+        assert_eq!(
+            alignment.operations[..3],
+            [
+                EditOperation::Keep {
+                    source: 0..1,
+                    target: 0..1
+                },
+                EditOperation::Keep {
+                    source: 1..2,
+                    target: 1..2
+                },
+                EditOperation::Delete {
+                    source: 2..3,
+                    target: 2..2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn needleman_wunsch() {
+        const SEED: [(&str, &str, (&str, &str)); 9] = [
+            ("", "", ("", "")),
+            ("", "abc", ("---", "abc")),
+            ("abc", "", ("abc", "---")),
+            ("abc", "abc", ("abc", "abc")),
+            ("abc", "xyz", ("abc", "xyz")),
+            ("abcdef", "abdef", ("abcdef", "ab-def")),
+            ("abcxxxxdef", "abcdef", ("abcxxxxdef", "abc----def")),
+            ("PN-1042", "PN1042A", ("PN-1042-", "PN-1042A")),
+            ("·ab·", "ab", ("·ab·", "-ab-")),
+        ];
+
+        let scores = AlignmentScores::default();
+        for (sut, other, expected) in SEED {
+            assert_eq!(
+                sut.needleman_wunsch(other, &scores, '-'),
+                (expected.0.to_string(), expected.1.to_string()),
+                "{sut} {other}"
+            );
+        }
+
+        assert_eq!(
+            "ac".needleman_wunsch("abc", &scores, "__"),
+            ("a__c".to_string(), "abc".to_string())
+        );
+    }
+
+    #[test]
+    fn fuzzy_match() {
+        const SEED: [(&str, &str, Option<&[usize]>); 14] = [
+            ("", "", Some(&[])),
+            ("abc", "", Some(&[])),
+            ("", "a", None),
+            ("abc", "abcd", None),
+            ("abc", "cb", None),
+            ("foobar", "FB", None),
+            ("FooBar", "FB", Some(&[0, 3])),
+            ("fooBar", "fb", Some(&[0, 3])),
+            ("git commit --amend", "gca", Some(&[0, 4, 13])),
+            ("a_b_c abc", "abc", Some(&[6, 7, 8])),
+            ("xaaabab", "ab", Some(&[3, 4])),
+            ("src/lib.rs", "lib", Some(&[4, 5, 6])),
+            ("·a·b", "ab", Some(&[2, 5])),
+            ("Äpfel", "äp", Some(&[0, 2])),
+        ];
+
+        for (sut, query, expected) in SEED {
+            assert_eq!(
+                sut.fuzzy_match(query).map(|m| m.positions),
+                expected.map(<[usize]>::to_vec),
+                "{sut} {query}"
+            );
+        }
+
+        // word starts and camelCase humps score more than plain chars.
+        let score = |sut: &str, query| sut.fuzzy_match(query).unwrap().score;
+        assert!(score("foo_bar", "fb") > score("foobar", "fb"));
+        assert!(score("fooBar", "fb") > score("foobar", "fb"));
+        // consecutive matches score more than scattered ones.
+        assert!(score("xfoox", "foo") > score("xfxoxo", "foo"));
+        assert_eq!(score("abc", ""), 0);
+
+        assert_eq!(
+            "foobar"
+                .fuzzy_match_with("FB", CaseMatching::Insensitive)
+                .map(|m| m.positions),
+            Some(vec![0, 3])
+        );
+        assert_eq!(
+            "FooBar".fuzzy_match_with("fb", CaseMatching::Sensitive),
+            None
+        );
+    }
+
+    #[test]
+    fn fuzzy_highlight() {
+        const SEED: [(&str, &[usize], &str); 6] = [
+            ("", &[], ""),
+            ("abc", &[], "abc"),
+            ("abc", &[0, 1, 2], "[abc]"),
+            ("abcdef", &[0, 2, 3, 5], "[a]b[cd]e[f]"),
+            ("·a·b", &[0, 2, 3], "[·a·]b"),
+            ("a🦀b", &[1], "a[🦀]b"),
+        ];
+
+        for (sut, positions, expected) in SEED {
+            assert_eq!(sut.fuzzy_highlight(positions, '[', "]"), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn fuzzy_highlight_unsorted() {
+        "abcdef".fuzzy_highlight(&[3, 1], '[', "]");
+    }
+
+    #[test]
+    #[should_panic]
+    fn fuzzy_highlight_out_of_bounds() {
+        "abc".fuzzy_highlight(&[3], '[', "]");
+    }
+
+    #[test]
+    #[should_panic]
+    fn fuzzy_highlight_out_of_bounds_run() {
+        "abc".fuzzy_highlight(&[2, 3], '[', "]");
+    }
+
+    #[test]
+    fn closest_matches() {
+        const COMMANDS: [&str; 9] = [
+            "build", "bench", "check", "clean", "doc", "new", "init", "run", "test",
+        ];
+
+        const SEED: [(&str, Option<usize>, usize, &[&str]); 13] = [
+            ("", None, 3, &[]),
+            ("buld", None, 3, &["build"]),
+            ("buidl", None, 3, &[]),
+            ("buidl", Some(2), 3, &["build"]),
+            ("biuld", Some(2), 1, &["build"]),
+            ("chek", None, 3, &["check"]),
+            ("cleen", None, 3, &["clean"]),
+            ("rn", None, 3, &["run"]),
+            ("tset", Some(2), 3, &["test"]),
+            ("xyz", None, 3, &[]),
+            ("ben", Some(2), 2, &["bench", "new"]),
+            ("ben", Some(2), 0, &[]),
+            ("bnch", Some(0), 3, &[]),
+        ];
+
+        for (sut, max_distance, limit, expected) in SEED {
+            assert_eq!(
+                sut.closest_matches(COMMANDS, max_distance, limit),
+                expected,
+                "{sut} {max_distance:?} {limit}"
+            );
+        }
+
+        // on ties, candidates sharing a longer prefix come first.
+        assert_eq!(
+            "tast".closest_matches(["last", "test", "tart"], None, 3),
+            ["tart", "test", "last"]
+        );
+
+        // queries of up to 5 chars allow one edit, queries of 6 to 8 chars two edits.
+        assert_eq!(
+            "abcd".closest_matches(["axcd", "axyd", "abcd"], None, 3),
+            ["abcd", "axcd"]
+        );
+        assert_eq!(
+            "abcde".closest_matches(["axcde", "axcye"], None, 3),
+            ["axcde"]
+        );
+        assert_eq!(
+            "abcdef".closest_matches(["axcyef", "axyzef"], None, 3),
+            ["axcyef"]
+        );
+    }
+
     #[test]
     fn hamming_distance() {
         const SEED: [(&str, &str, Option<usize>); 16] = [
@@ -1354,6 +3242,187 @@ mod tests {
                 expected.is_none()
             });
             assert_eq!(sut.hamming_distance(other), expected);
+            assert_eq!(sut.hamming_distance_by(other, Unit::Char), expected);
+        }
+    }
+
+    #[test]
+    fn hamming_distance_by() {
+        const SEED: [(&str, &str, Unit, Option<usize>, usize); 12] = [
+            ("", "", Unit::Byte, Some(0), 0),
+            ("", "", Unit::Grapheme, Some(0), 0),
+            ("4006381333931", "4006381333981", Unit::Byte, Some(1), 1),
+            ("ring", "bring", Unit::Byte, None, 5),
+            ("ring", "bring", Unit::Grapheme, None, 5),
+            ("kitten", "sitting", Unit::Char, None, 3),
+            ("Āgain", "āgain", Unit::Byte, Some(1), 1),
+            ("Āgain", "Źgain", Unit::Byte, Some(2), 2),
+            ("Āgain", "again", Unit::Byte, None, 6),
+            ("cafe\u{301}", "caf\u{e9}s", Unit::Char, Some(2), 2),
+            ("cafe\u{301}", "caf\u{e9}", Unit::Grapheme, Some(1), 1),
+            ("🇮🇹🇫🇷", "🇮🇹🇩🇪!", Unit::Grapheme, None, 2),
+        ];
+
+        for (sut, other, unit, expected, padded) in SEED {
+            assert_eq!(sut.hamming_distance_by(other, unit), expected);
+            assert_eq!(other.hamming_distance_by(sut, unit), expected);
+            assert_eq!(sut.padded_hamming_distance(other, unit), padded);
+            assert_eq!(other.padded_hamming_distance(sut, unit), padded);
+        }
+    }
+
+    #[test]
+    fn metrics_with_options() {
+        let all = CompareOptions::new()
+            .case_insensitive()
+            .accent_insensitive()
+            .collapse_whitespace()
+            .ignore_punctuation();
+
+        // (sut, other, [levenshtein, osa, damerau-levenshtein, lcs length])
+        const SEED: [(&str, &str, [usize; 4]); 6] = [
+            ("", " \t ", [0, 0, 0, 0]),
+            ("Hello, World!", "hello world", [0, 0, 0, 11]),
+            ("  Déjà   vu ", "deja vu", [0, 0, 0, 7]),
+            ("Naïve — café", "naive cafe", [0, 0, 0, 10]),
+            ("Teh, Quick!", "the quick", [2, 1, 1, 8]),
+            ("ÉCOLE", "école", [0, 0, 0, 5]),
+        ];
+
+        for (sut, other, [levenshtein, osa, damerau, lcs]) in SEED {
+            assert_eq!(
+                sut.levenshtein_distance_with(other, all),
+                levenshtein,
+                "{sut} {other}"
+            );
+            assert_eq!(sut.osa_distance_with(other, all), osa, "{sut} {other}");
+            assert_eq!(
+                sut.damerau_levenshtein_distance_with(other, all),
+                damerau,
+                "{sut} {other}"
+            );
+            assert_eq!(sut.lcs_length_with(other, all), lcs, "{sut} {other}");
+        }
+
+        // the metrics compare the strings as if they had been normalized beforehand.
+        let strings = random_strings(30, 80)
+            .into_iter()
+            .map(|s| s.replace('d', " "))
+            .collect::<Vec<_>>();
+
+        for options in (0..16).map(|bits| CompareOptions {
+            case_insensitive: bits & 1 != 0,
+            accent_insensitive: bits & 2 != 0,
+            collapse_whitespace: bits & 4 != 0,
+            ignore_punctuation: bits & 8 != 0,
+        }) {
+            for pair in strings.windows(2) {
+                let (sut, other) = (pair[0].as_str(), pair[1].as_str());
+                let normalized = options.chars(sut).collect::<String>();
+                let other_normalized = options.chars(other).collect::<String>();
+                let expected = normalized.as_str();
+
+                assert_eq!(
+                    sut.levenshtein_distance_with(other, options),
+                    expected.levenshtein_distance(&other_normalized)
+                );
+                assert_eq!(
+                    sut.osa_distance_with(other, options),
+                    expected.osa_distance(&other_normalized)
+                );
+                assert_eq!(
+                    sut.damerau_levenshtein_distance_with(other, options),
+                    expected.damerau_levenshtein_distance(&other_normalized)
+                );
+                assert_eq!(
+                    sut.hamming_distance_with(other, options),
+                    expected.hamming_distance(&other_normalized)
+                );
+                assert_eq!(
+                    sut.jaro_similarity_with(other, options),
+                    expected.jaro_similarity(&other_normalized)
+                );
+                assert_eq!(
+                    sut.jaro_winkler_similarity_with(other, 0.1, 0.7, options),
+                    expected.jaro_winkler_similarity(&other_normalized, 0.1, 0.7)
+                );
+                assert_eq!(
+                    sut.lcs_length_with(other, options),
+                    expected.lcs_length(&other_normalized)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn metrics_by_unit() {
+        // (sut, other, unit, [levenshtein, osa, damerau-levenshtein, lcs length])
+        const SEED: [(&str, &str, Unit, [usize; 4]); 10] = [
+            ("", "", Unit::Byte, [0, 0, 0, 0]),
+            ("", "", Unit::Grapheme, [0, 0, 0, 0]),
+            ("ab", "ba", Unit::Byte, [2, 1, 1, 1]),
+            ("ca", "abc", Unit::Grapheme, [3, 3, 2, 1]),
+            ("Āgain", "āgain", Unit::Byte, [1, 1, 1, 5]),
+            ("cafe\u{301}", "caf\u{e9}", Unit::Byte, [3, 3, 3, 3]),
+            ("cafe\u{301}", "caf\u{e9}", Unit::Char, [2, 2, 2, 3]),
+            ("cafe\u{301}", "caf\u{e9}", Unit::Grapheme, [1, 1, 1, 3]),
+            ("🇮🇹🇫🇷", "🇫🇷🇮🇹", Unit::Grapheme, [2, 1, 1, 1]),
+            (
+                "👨\u{200d}👩\u{200d}👧",
+                "👨\u{200d}👩\u{200d}👦",
+                Unit::Grapheme,
+                [1, 1, 1, 0],
+            ),
+        ];
+
+        for (sut, other, unit, [levenshtein, osa, damerau, lcs]) in SEED {
+            assert_eq!(
+                sut.levenshtein_distance_by(other, unit),
+                levenshtein,
+                "{sut} {other}"
+            );
+            assert_eq!(sut.osa_distance_by(other, unit), osa, "{sut} {other}");
+            assert_eq!(
+                sut.damerau_levenshtein_distance_by(other, unit),
+                damerau,
+                "{sut} {other}"
+            );
+            assert_eq!(sut.lcs_length_by(other, unit), lcs, "{sut} {other}");
+        }
+
+        // splitting in graphemes, the strings compare as if the accented letters were single chars.
+        let (sut, other) = ("cafe\u{301}", "caf\u{e9}");
+        assert_eq!(
+            sut.jaro_similarity_by(other, Unit::Grapheme),
+            "cafx".jaro_similarity("cafy")
+        );
+        assert_eq!(
+            sut.jaro_winkler_similarity_by(other, 0.1, 0.7, Unit::Grapheme),
+            "cafx".jaro_winkler_similarity("cafy", 0.1, 0.7)
+        );
+
+        // the bytes and the graphemes of ASCII strings with no "\r\n" are their chars.
+        for (sut, other) in [("kitten", "sitting"), ("martha", "marhta"), ("ab", "ba")] {
+            for unit in [Unit::Byte, Unit::Grapheme] {
+                assert_eq!(
+                    sut.levenshtein_distance_by(other, unit),
+                    sut.levenshtein_distance(other)
+                );
+                assert_eq!(sut.osa_distance_by(other, unit), sut.osa_distance(other));
+                assert_eq!(
+                    sut.damerau_levenshtein_distance_by(other, unit),
+                    sut.damerau_levenshtein_distance(other)
+                );
+                assert_eq!(
+                    sut.jaro_similarity_by(other, unit),
+                    sut.jaro_similarity(other)
+                );
+                assert_eq!(
+                    sut.jaro_winkler_similarity_by(other, 0.1, 0.7, unit),
+                    sut.jaro_winkler_similarity(other, 0.1, 0.7)
+                );
+                assert_eq!(sut.lcs_length_by(other, unit), sut.lcs_length(other));
+            }
         }
     }
 
@@ -1379,8 +3448,96 @@ mod tests {
     }
 
     #[test]
-    fn longest_common_subsequence() {
-        const SEED: [(&str, &str, &str); 18] = [
+    #[should_panic]
+    fn qgram_empty() {
+        QGram::new(0);
+    }
+
+    #[test]
+    fn qgram_frequencies() {
+        type Frequencies = &'static [(&'static str, usize)];
+
+        const SEED: [(&str, QGram, Frequencies); 7] = [
+            ("", QGram::new(1), &[]),
+            ("a", QGram::new(2), &[]),
+            ("abab", QGram::new(2), &[("ab", 2), ("ba", 1)]),
+            (
+                "ab",
+                QGram::new(3).padded('$'),
+                &[("$$a", 1), ("$ab", 1), ("ab$", 1), ("b$$", 1)],
+            ),
+            ("·x·", QGram::new(1).padded('$'), &[("x", 1), ("·", 2)]),
+            (
+                "cafe\u{301}",
+                QGram::new(2).by(Unit::Grapheme),
+                &[("af", 1), ("ca", 1), ("fe\u{301}", 1)],
+            ),
+            (
+                "a·",
+                QGram::new(2).by(Unit::Byte),
+                &[("a\u{fffd}", 1), ("·", 1)],
+            ),
+        ];
+
+        for (sut, qgram, expected) in SEED {
+            assert_eq!(
+                sut.qgram_frequencies::<BTreeMap<_, _>>(qgram),
+                expected.iter().map(|(g, f)| (g.to_string(), *f)).collect()
+            );
+
+            assert_eq!(
+                sut.qgram_frequencies::<HashMap<_, _>>(qgram),
+                expected.iter().map(|(g, f)| (g.to_string(), *f)).collect()
+            );
+        }
+    }
+
+    #[test]
+    fn qgram_similarities() {
+        // (sut, other, qgram, [jaccard, sorensen-dice, cosine, overlap])
+        let seed: [(&str, &str, QGram, [f64; 4]); 7] = [
+            ("", "", QGram::new(2), [1.0, 1.0, 1.0, 1.0]),
+            ("a", "b", QGram::new(2), [0.0, 0.0, 0.0, 0.0]),
+            ("a", "a", QGram::new(2), [1.0, 1.0, 1.0, 1.0]),
+            (
+                "night",
+                "nacht",
+                QGram::new(2),
+                [1.0 / 7.0, 0.25, 0.25, 0.25],
+            ),
+            (
+                "night",
+                "nacht",
+                QGram::new(2).padded('#'),
+                [3.0 / 9.0, 0.5, 0.5, 0.5],
+            ),
+            (
+                "abab",
+                "ab",
+                QGram::new(2),
+                [0.5, 2.0 / 3.0, 2.0 / 5f64.sqrt(), 1.0],
+            ),
+            (
+                "Āb·",
+                "Āb",
+                QGram::new(1),
+                [2.0 / 3.0, 0.8, 2.0 / 6f64.sqrt(), 1.0],
+            ),
+        ];
+
+        for (sut, other, qgram, [jaccard, dice, cosine, overlap]) in seed {
+            for (sut, other) in [(sut, other), (other, sut)] {
+                assert_eq!(sut.jaccard_similarity(other, qgram), jaccard);
+                assert_eq!(sut.sorensen_dice_similarity(other, qgram), dice);
+                assert_eq!(sut.cosine_similarity(other, qgram), cosine);
+                assert_eq!(sut.overlap_coefficient(other, qgram), overlap);
+            }
+        }
+    }
+
+    #[test]
+    fn longest_common_substring() {
+        const SEED: [(&str, &str, &str); 19] = [
             ("", "", ""),
             ("bar", "", ""),
             ("", "bar", ""),
@@ -1399,6 +3556,8 @@ mod tests {
             ("0123456789", "012345", "012345"),
             ("0123456789", "456789", "456789"),
             ("0123456789", "345678", "345678"),
+            // lengths are measured in bytes, not in chars.
+            ("ĀĀ-xyz", "xyz+ĀĀ", "ĀĀ"),
         ];
 
         for (sut, other, expected) in SEED {
@@ -1406,6 +3565,366 @@ mod tests {
         }
     }
 
+    #[test]
+    fn longest_common_substring_all() {
+        const SEED: [(&str, &str, &[&str]); 10] = [
+            ("", "", &[]),
+            ("bar", "", &[]),
+            ("foo", "bar", &[]),
+            ("hello", "hello", &["hello"]),
+            ("lorem ipsum dolor", "ipsum", &["ipsum"]),
+            ("abcxyz", "xyzabc", &["abc", "xyz"]),
+            ("abab", "ba", &["ba"]),
+            ("xĀyȀ", "Āyx·Ȁ", &["Āy"]),
+            ("Āx·yȀ", "Ȁ·Ā", &["Ā", "·", "Ȁ"]),
+            ("0123456789", "890123", &["0123"]),
+        ];
+
+        for (sut, other, expected) in SEED {
+            assert_eq!(
+                sut.longest_common_substring_all(other),
+                expected,
+                "{sut} {other}"
+            );
+        }
+    }
+
+    #[test]
+    fn longest_common_substring_many() {
+        const SEED: [(&str, &[&str], &str); 9] = [
+            ("", &[], ""),
+            ("hello", &[], "hello"),
+            ("hello", &[""], ""),
+            ("hello", &["hello", "hello"], "hello"),
+            ("sparrow", &["crow", "arrow"], "row"),
+            ("lorem ipsum dolor", &["ipsum", "dolor sit amet"], "m"),
+            ("xĀyȀ", &["Āyx·Ȁ", "zĀy"], "Āy"),
+            ("0123456789", &["890123", "12345"], "123"),
+            ("foo", &["bar", "foo"], ""),
+        ];
+
+        for (sut, others, expected) in SEED {
+            assert_eq!(sut.longest_common_substring_many(others), expected);
+        }
+    }
+
+    #[test]
+    fn longest_common_subsequence() {
+        const SEED: [(&str, &str, &str); 16] = [
+            ("", "", ""),
+            ("bar", "", ""),
+            ("", "bar", ""),
+            ("foo", "bar", ""),
+            ("hello", "hello", "hello"),
+            ("lorem ipsum dolor", "ipsum", "ipsum"),
+            ("ipsum", "lorem ipsum dolor", "ipsum"),
+            ("spĀm", "spām", "spm"),
+            ("bananĀ", "bananā", "banan"),
+            ("Āx", "Ȁx", "x"),
+            ("AGGTAB", "GXTXAYB", "GTAB"),
+            ("ABCBDAB", "BDCABA", "BCBA"),
+            ("kitten", "sitting", "ittn"),
+            ("Hello·World!", "·World", "·World"),
+            ("·a·b·c·", "abc", "abc"),
+            ("0123456789", "1357", "1357"),
+        ];
+
+        for (sut, other, expected) in SEED {
+            let (subsequence, matches) = sut.longest_common_subsequence(other);
+            assert_eq!(subsequence, expected, "{sut} {other}");
+            assert_eq!(subsequence.chars().count(), matches.len());
+
+            for ((i, j), c) in matches.iter().zip(subsequence.chars()) {
+                assert!(sut[*i..].starts_with(c), "{sut} {other}");
+                assert!(other[*j..].starts_with(c), "{sut} {other}");
+            }
+
+            assert!(matches
+                .windows(2)
+                .all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+        }
+
+        assert_eq!(
+            "·a·b".longest_common_subsequence("xaby"),
+            (String::from("ab"), vec![(2, 1), (5, 2)])
+        );
+    }
+
+    #[test]
+    fn lcs_length() {
+        const SEED: [(&str, &str, usize); 12] = [
+            ("", "", 0),
+            ("bar", "", 0),
+            ("", "bar", 0),
+            ("foo", "bar", 0),
+            ("hello", "hello", 5),
+            ("lorem ipsum dolor", "ipsum", 5),
+            ("spĀm", "spām", 3),
+            ("AGGTAB", "GXTXAYB", 4),
+            ("ABCBDAB", "BDCABA", 4),
+            ("kitten", "sitting", 4),
+            ("·a·b·c·", "abc", 3),
+            ("0123456789", "1357", 4),
+        ];
+
+        for (sut, other, expected) in SEED {
+            assert_eq!(sut.lcs_length(other), expected, "{sut} {other}");
+            assert_eq!(other.lcs_length(sut), expected, "{sut} {other}");
+        }
+    }
+
+    #[test]
+    fn ratcliff_obershelp_similarity() {
+        // the ratios computed by Python's difflib.SequenceMatcher.
+        const SEED: [(&str, &str, f64); 10] = [
+            ("", "", 1.0),
+            ("", "abc", 0.0),
+            ("abcd", "bcde", 0.75),
+            ("qabxcd", "abycdf", 0.6666666666666666),
+            (
+                "private Thread currentThread;",
+                "private volatile Thread currentThread;",
+                0.8656716417910447,
+            ),
+            ("GESTALT PATTERN MATCHING", "GESTALT PRACTICE", 0.6),
+            ("WIKIMEDIA", "WIKIMANIA", 0.7777777777777778),
+            ("abxcd", "abcd", 0.8888888888888888),
+            ("crème brûlée", "creme brulee", 0.75),
+            ("tide", "diet", 0.25),
+        ];
+
+        for (sut, other, expected) in SEED {
+            assert_eq!(
+                sut.ratcliff_obershelp_similarity(other),
+                expected,
+                "{sut} {other}"
+            );
+        }
+    }
+
+    #[test]
+    fn matching_blocks() {
+        // the (source, target, size) triples computed by Python's difflib.SequenceMatcher.
+        type Blocks = &'static [(usize, usize, usize)];
+        const SEED: [(&str, &str, Blocks); 8] = [
+            ("", "", &[(0, 0, 0)]),
+            ("", "abc", &[(0, 3, 0)]),
+            ("abcd", "bcde", &[(1, 0, 3), (4, 4, 0)]),
+            (
+                "GESTALT PATTERN MATCHING",
+                "GESTALT PRACTICE",
+                &[(0, 0, 9), (9, 10, 1), (10, 12, 1), (12, 15, 1), (24, 16, 0)],
+            ),
+            ("WIKIMEDIA", "WIKIMANIA", &[(0, 0, 5), (7, 7, 2), (9, 9, 0)]),
+            ("tide", "diet", &[(0, 3, 1), (4, 4, 0)]),
+            // byte indices, "è" spans 2 bytes.
+            ("crèmè", "cremè", &[(0, 0, 2), (4, 3, 3), (7, 6, 0)]),
+            // the blocks are the longest in chars, "ĀĀ" is longer in bytes only.
+            ("ĀĀ-xyz", "xyz+ĀĀ", &[(5, 0, 3), (8, 8, 0)]),
+        ];
+
+        for (sut, other, expected) in SEED {
+            let expected = expected
+                .iter()
+                .map(|&(i, j, size)| MatchingBlock {
+                    source: i..i + size,
+                    target: j..j + size,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(sut.matching_blocks(other), expected, "{sut} {other}");
+        }
+    }
+
+    #[test]
+    fn opcodes() {
+        use Opcode::{Delete, Equal, Insert, Replace};
+
+        assert!("".opcodes("").is_empty());
+        assert_eq!(
+            "".opcodes("abc"),
+            [Insert {
+                source: 0..0,
+                target: 0..3
+            }]
+        );
+        assert_eq!(
+            "private Thread currentThread;".opcodes("private volatile Thread currentThread;"),
+            [
+                Equal {
+                    source: 0..6,
+                    target: 0..6
+                },
+                Insert {
+                    source: 6..6,
+                    target: 6..15
+                },
+                Equal {
+                    source: 6..29,
+                    target: 15..38
+                },
+            ]
+        );
+        assert_eq!(
+            "WIKIMEDIA".opcodes("WIKIMANIA"),
+            [
+                Equal {
+                    source: 0..5,
+                    target: 0..5
+                },
+                Replace {
+                    source: 5..7,
+                    target: 5..7
+                },
+                Equal {
+                    source: 7..9,
+                    target: 7..9
+                },
+            ]
+        );
+        assert_eq!(
+            "tide".opcodes("diet"),
+            [
+                Insert {
+                    source: 0..0,
+                    target: 0..3
+                },
+                Equal {
+                    source: 0..1,
+                    target: 3..4
+                },
+                Delete {
+                    source: 1..4,
+                    target: 4..4
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn soundex() {
+        const SEED: [(&str, &str); 13] = [
+            ("", ""),
+            ("123", ""),
+            ("Lee", "L000"),
+            ("Robert", "R163"),
+            ("Rupert", "R163"),
+            ("Rubin", "R150"),
+            ("Ashcraft", "A261"),
+            ("Tymczak", "T522"),
+            ("Pfister", "P236"),
+            ("Honeyman", "H555"),
+            ("smith", "S530"),
+            ("Smyth", "S530"),
+            ("Ñuñez", "U200"),
+        ];
+
+        for (sut, expected) in SEED {
+            assert_eq!(sut.soundex(), expected, "{sut}");
+        }
+    }
+
+    #[test]
+    fn refined_soundex() {
+        const SEED: [(&str, &str); 10] = [
+            ("", ""),
+            ("testing", "T6036084"),
+            ("TESTING", "T6036084"),
+            ("The", "T60"),
+            ("quick", "Q503"),
+            ("brown", "B1908"),
+            ("fox", "F205"),
+            ("jumped", "J408106"),
+            ("lazy", "L7050"),
+            ("dogs", "D6043"),
+        ];
+
+        for (sut, expected) in SEED {
+            assert_eq!(sut.refined_soundex(), expected, "{sut}");
+        }
+    }
+
+    #[test]
+    fn metaphone() {
+        const SEED: [(&str, &str); 16] = [
+            ("", ""),
+            ("Smith", "SM0"),
+            ("Smyth", "SM0"),
+            ("howl", "HL"),
+            ("The", "0"),
+            ("quick", "KK"),
+            ("brown", "BRN"),
+            ("fox", "FKS"),
+            ("jumped", "JMPT"),
+            ("over", "OFR"),
+            ("dogs", "TKS"),
+            ("Knight", "NT"),
+            ("science", "SNS"),
+            ("edge", "EJ"),
+            ("thumb", "0M"),
+            ("Xavier", "SFR"),
+        ];
+
+        for (sut, expected) in SEED {
+            assert_eq!(sut.metaphone(), expected, "{sut}");
+        }
+    }
+
+    #[test]
+    fn double_metaphone() {
+        const SEED: [(&str, &str, &str); 18] = [
+            ("", "", ""),
+            ("Smith", "SM0", "XMT"),
+            ("Schmidt", "XMT", "SMT"),
+            ("Thomas", "TMS", "TMS"),
+            ("Jose", "HS", "HS"),
+            ("Czerny", "SRN", "XRN"),
+            ("focaccia", "FKX", "FKX"),
+            ("Michael", "MKL", "MXL"),
+            ("Xavier", "SF", "SFR"),
+            ("Arnow", "ARN", "ARNF"),
+            ("Gallegos", "KLKS", "KKS"),
+            ("edge", "AJ", "AJ"),
+            ("Campbell", "KMPL", "KMPL"),
+            ("Jankelowicz", "JNKL", "ANKL"),
+            ("Filipowicz", "FLPT", "FLPF"),
+            ("Hugh", "H", "H"),
+            ("Zhao", "J", "J"),
+            ("François", "FRNS", "FRNS"),
+        ];
+
+        for (sut, primary, alternate) in SEED {
+            assert_eq!(sut.double_metaphone(), primary, "{sut}");
+            assert_eq!(sut.double_metaphone_alternate(), alternate, "{sut}");
+            assert_eq!(
+                sut.double_metaphone_codes(),
+                (primary.to_string(), alternate.to_string()),
+                "{sut}"
+            );
+        }
+    }
+
+    #[test]
+    fn nysiis() {
+        const SEED: [(&str, &str); 12] = [
+            ("", ""),
+            ("Bishop", "BASAP"),
+            ("Knight", "NAGT"),
+            ("Macintosh", "MCANT"),
+            ("Brian", "BRAN"),
+            ("Brown", "BRAN"),
+            ("Brownie", "BRANY"),
+            ("Smith", "SNAT"),
+            ("Schmidt", "SNAD"),
+            ("Phillips", "FALAP"),
+            ("Kelly", "CALY"),
+            ("Christopherson", "CRASTA"),
+        ];
+
+        for (sut, expected) in SEED {
+            assert_eq!(sut.nysiis(), expected, "{sut}");
+        }
+    }
+
     #[test]
     fn join_strings() {
         let result = ", ".join(["one", "two", "three"]);
@@ -0,0 +1,778 @@
+use crate::suffix_automaton::SuffixAutomaton;
+use crate::{CompareOptions, EditCosts, QGram, StrExt, UniformCosts, Unit};
+
+/// The `StringMetric` trait provides a common interface to the string metrics offered by this crate,
+/// so that the code relying on them can switch metric by changing a type parameter.
+///
+/// Every metric exposes its natural `distance`, whose type depends on the metric, and a
+/// `normalized_similarity` ranging from `0.0` (no similarity at all) to `1.0` (identical strings),
+/// which can be compared across different metrics.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{DamerauLevenshtein, JaroWinkler, Levenshtein, StringMetric};
+///
+/// fn best_match<'a, M: StringMetric + Default>(query: &str, candidates: &[&'a str]) -> &'a str {
+///     let metric = M::default();
+///     candidates
+///         .iter()
+///         .copied()
+///         .max_by(|l, r| {
+///             let l = metric.normalized_similarity(query, l);
+///             let r = metric.normalized_similarity(query, r);
+///             l.partial_cmp(&r).unwrap()
+///         })
+///         .unwrap()
+/// }
+///
+/// assert_eq!(best_match::<DamerauLevenshtein>("buidl", &["build", "guild", "bundle"]), "build");
+/// assert_eq!(best_match::<JaroWinkler>("buidl", &["build", "guild", "bundle"]), "build");
+/// assert_eq!(Levenshtein.distance("kitten", "sitting"), 3);
+/// ```
+pub trait StringMetric {
+    /// The type of the distance computed by the metric.
+    type Distance: Copy + PartialOrd;
+
+    /// Computes the distance between `a` and `b`.
+    fn distance(&self, a: &str, b: &str) -> Self::Distance;
+
+    /// Computes the distance between `a` and `b` if it does not exceed `max`,
+    /// otherwise this function returns `None`.
+    /// Metrics able to give up as soon as the distance exceeds `max` override it.
+    fn distance_within(&self, a: &str, b: &str, max: Self::Distance) -> Option<Self::Distance> {
+        Some(self.distance(a, b)).filter(|distance| *distance <= max)
+    }
+
+    /// Computes the similarity between `a` and `b` normalized in the range `0.0..=1.0`,
+    /// where `1.0` means that the strings are identical according to the metric.
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64;
+}
+
+/// The Levenshtein distance, see `StrExt::levenshtein_distance`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Levenshtein;
+
+/// The optimal string alignment distance, see `StrExt::osa_distance`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OptimalStringAlignment;
+
+/// The unrestricted Damerau-Levenshtein distance, see `StrExt::damerau_levenshtein_distance`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DamerauLevenshtein;
+
+/// The weighted Levenshtein distance priced by the wrapped edit costs,
+/// see `StrExt::weighted_levenshtein_distance`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WeightedLevenshtein<C = UniformCosts>(pub C);
+
+/// The Hamming distance, see `StrExt::hamming_distance`.
+/// Strings of different lengths have no similarity at all.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Hamming;
+
+/// The Jaro similarity, see `StrExt::jaro_similarity`.
+/// The distance is the complement of the similarity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Jaro;
+
+/// The Jaro-Winkler similarity, see `StrExt::jaro_winkler_similarity`.
+/// The distance is the complement of the similarity.
+///
+/// The default values for `prefix_scale` and `boost_threshold` are the original
+/// ones by Winkler, respectively `0.1` and `0.7`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JaroWinkler {
+    pub prefix_scale: f64,
+    pub boost_threshold: f64,
+}
+
+/// The longest common substring, see `StrExt::longest_common_substring`, measured in chars
+/// rather than in bytes. The distance is the number of chars of both strings that are not
+/// part of their longest common substring.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LongestCommonSubstring;
+
+/// The longest common subsequence, see `StrExt::longest_common_subsequence`.
+/// The distance is the number of chars of both strings that are not part of
+/// their longest common subsequence, that is the number of insertions and deletions
+/// needed to turn one string into the other.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LongestCommonSubsequence;
+
+/// The Ratcliff/Obershelp similarity, see `StrExt::ratcliff_obershelp_similarity`.
+/// The distance is the complement of the similarity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RatcliffObershelp;
+
+/// The Jaccard similarity over the q-grams of the wrapped configuration,
+/// see `StrExt::jaccard_similarity`. The distance is the complement of the similarity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Jaccard(pub QGram);
+
+/// The Sørensen-Dice similarity over the q-grams of the wrapped configuration,
+/// see `StrExt::sorensen_dice_similarity`. The distance is the complement of the similarity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SorensenDice(pub QGram);
+
+/// The cosine similarity over the q-grams of the wrapped configuration,
+/// see `StrExt::cosine_similarity`. The distance is the complement of the similarity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Cosine(pub QGram);
+
+/// The overlap coefficient over the q-grams of the wrapped configuration,
+/// see `StrExt::overlap_coefficient`. The distance is the complement of the similarity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Overlap(pub QGram);
+
+/// The wrapped metric comparing the strings split in the given unit rather than in chars,
+/// see `Unit`. It is available for `Levenshtein`, `OptimalStringAlignment`, `DamerauLevenshtein`,
+/// `Hamming`, `Jaro`, `JaroWinkler` and `LongestCommonSubsequence`, while the q-gram based
+/// metrics take the unit from their `QGram`.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{ByUnit, Levenshtein, StringMetric, Unit};
+///
+/// // "é" as "e" followed by a combining acute accent.
+/// assert_eq!(Levenshtein.distance("cafe\u{301}", "café"), 2);
+/// assert_eq!(ByUnit(Levenshtein, Unit::Grapheme).distance("cafe\u{301}", "café"), 1);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ByUnit<M>(pub M, pub Unit);
+
+/// The wrapped metric comparing the strings normalized according to the given options,
+/// see `CompareOptions`. It is available for the same metrics as `ByUnit`, normalized
+/// similarities being computed over the lengths of the normalized strings.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{CompareOptions, Levenshtein, Normalized, StringMetric};
+///
+/// let metric = Normalized(Levenshtein, CompareOptions::new().case_insensitive());
+/// assert_eq!(metric.distance("Kitten", "sitting"), 3);
+/// assert_eq!(metric.distance("Kitten", "kitten"), 0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Normalized<M>(pub M, pub CompareOptions);
+
+impl Default for JaroWinkler {
+    fn default() -> Self {
+        Self {
+            prefix_scale: 0.1,
+            boost_threshold: 0.7,
+        }
+    }
+}
+
+/// Normalizes an edit distance bounded by the length of the longest string.
+fn normalize_edit_distance(distance: usize, a: &str, b: &str) -> f64 {
+    normalize_edit_distance_by(distance, a, b, Unit::Char)
+}
+
+/// Normalizes an edit distance bounded by the length in `unit` of the longest string.
+fn normalize_edit_distance_by(distance: usize, a: &str, b: &str, unit: Unit) -> f64 {
+    normalize_by_len(distance, usize::max(unit.count(a), unit.count(b)))
+}
+
+/// Normalizes an edit distance bounded by the length of the longest normalized string.
+fn normalize_edit_distance_with(distance: usize, a: &str, b: &str, options: CompareOptions) -> f64 {
+    let (a, b) = (options.chars(a).count(), options.chars(b).count());
+    normalize_by_len(distance, usize::max(a, b))
+}
+
+/// Bounds a Hamming distance by `max`, where `None` stands for an infinite distance.
+fn hamming_distance_within(distance: Option<usize>, max: Option<usize>) -> Option<Option<usize>> {
+    match (distance, max) {
+        (_, None) => Some(distance),
+        (Some(d), Some(max)) if d <= max => Some(distance),
+        _ => None,
+    }
+}
+
+/// Normalizes an edit distance bounded by `len`.
+fn normalize_by_len(distance: usize, len: usize) -> f64 {
+    if len == 0 {
+        1.0
+    } else {
+        1.0 - distance as f64 / len as f64
+    }
+}
+
+impl StringMetric for Levenshtein {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.levenshtein_distance(b)
+    }
+
+    fn distance_within(&self, a: &str, b: &str, max: Self::Distance) -> Option<Self::Distance> {
+        a.levenshtein_distance_within(b, max)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        normalize_edit_distance(self.distance(a, b), a, b)
+    }
+}
+
+impl StringMetric for OptimalStringAlignment {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.osa_distance(b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        normalize_edit_distance(self.distance(a, b), a, b)
+    }
+}
+
+impl StringMetric for DamerauLevenshtein {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.damerau_levenshtein_distance(b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        normalize_edit_distance(self.distance(a, b), a, b)
+    }
+}
+
+impl<C: EditCosts> StringMetric for WeightedLevenshtein<C> {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.weighted_levenshtein_distance(b, &self.0)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        // deleting every char of `a` and inserting every char of `b` is always a viable
+        // (although seldom optimal) way of turning `a` into `b`, hence an upper bound.
+        let worst = a.chars().map(|c| self.0.deletion(c)).sum::<f64>()
+            + b.chars().map(|c| self.0.insertion(c)).sum::<f64>();
+
+        if worst <= 0.0 {
+            1.0
+        } else {
+            1.0 - self.distance(a, b) / worst
+        }
+    }
+}
+
+impl StringMetric for Hamming {
+    type Distance = Option<usize>;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.hamming_distance(b)
+    }
+
+    fn distance_within(&self, a: &str, b: &str, max: Self::Distance) -> Option<Self::Distance> {
+        hamming_distance_within(self.distance(a, b), max)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        match self.distance(a, b) {
+            Some(distance) => normalize_edit_distance(distance, a, b),
+            None => 0.0,
+        }
+    }
+}
+
+impl StringMetric for Jaro {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        a.jaro_similarity(b)
+    }
+}
+
+impl StringMetric for JaroWinkler {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        a.jaro_winkler_similarity(b, self.prefix_scale, self.boost_threshold)
+            .min(1.0)
+    }
+}
+
+impl StringMetric for LongestCommonSubstring {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        let common = longest_common_substring_len(a, b);
+        a.chars().count() + b.chars().count() - 2 * common
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        let len = a.chars().count() + b.chars().count();
+
+        if len == 0 {
+            1.0
+        } else {
+            let common = longest_common_substring_len(a, b);
+            2.0 * common as f64 / len as f64
+        }
+    }
+}
+
+/// Returns the length in chars of the longest common substring of `a` and `b`, which unlike
+/// the one in bytes does not depend on the order of the strings when there are many.
+fn longest_common_substring_len(a: &str, b: &str) -> usize {
+    SuffixAutomaton::new(a).longest_match(b).chars().count()
+}
+
+impl StringMetric for LongestCommonSubsequence {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.chars().count() + b.chars().count() - 2 * a.lcs_length(b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        let len = a.chars().count() + b.chars().count();
+
+        if len == 0 {
+            1.0
+        } else {
+            2.0 * a.lcs_length(b) as f64 / len as f64
+        }
+    }
+}
+
+impl StringMetric for RatcliffObershelp {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        a.ratcliff_obershelp_similarity(b)
+    }
+}
+
+impl StringMetric for Jaccard {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        a.jaccard_similarity(b, self.0)
+    }
+}
+
+impl StringMetric for SorensenDice {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        a.sorensen_dice_similarity(b, self.0)
+    }
+}
+
+impl StringMetric for Cosine {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        a.cosine_similarity(b, self.0)
+    }
+}
+
+impl StringMetric for Overlap {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        a.overlap_coefficient(b, self.0)
+    }
+}
+
+impl StringMetric for ByUnit<Levenshtein> {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.levenshtein_distance_by(b, self.1)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        normalize_edit_distance_by(self.distance(a, b), a, b, self.1)
+    }
+}
+
+impl StringMetric for ByUnit<OptimalStringAlignment> {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.osa_distance_by(b, self.1)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        normalize_edit_distance_by(self.distance(a, b), a, b, self.1)
+    }
+}
+
+impl StringMetric for ByUnit<DamerauLevenshtein> {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.damerau_levenshtein_distance_by(b, self.1)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        normalize_edit_distance_by(self.distance(a, b), a, b, self.1)
+    }
+}
+
+impl StringMetric for ByUnit<Hamming> {
+    type Distance = Option<usize>;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.hamming_distance_by(b, self.1)
+    }
+
+    fn distance_within(&self, a: &str, b: &str, max: Self::Distance) -> Option<Self::Distance> {
+        hamming_distance_within(self.distance(a, b), max)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        match self.distance(a, b) {
+            Some(distance) => normalize_edit_distance_by(distance, a, b, self.1),
+            None => 0.0,
+        }
+    }
+}
+
+impl StringMetric for ByUnit<Jaro> {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        a.jaro_similarity_by(b, self.1)
+    }
+}
+
+impl StringMetric for ByUnit<JaroWinkler> {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        let ByUnit(metric, unit) = self;
+        a.jaro_winkler_similarity_by(b, metric.prefix_scale, metric.boost_threshold, *unit)
+            .min(1.0)
+    }
+}
+
+impl StringMetric for ByUnit<LongestCommonSubsequence> {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        self.1.count(a) + self.1.count(b) - 2 * a.lcs_length_by(b, self.1)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        let len = self.1.count(a) + self.1.count(b);
+
+        if len == 0 {
+            1.0
+        } else {
+            2.0 * a.lcs_length_by(b, self.1) as f64 / len as f64
+        }
+    }
+}
+
+impl StringMetric for Normalized<Levenshtein> {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.levenshtein_distance_with(b, self.1)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        normalize_edit_distance_with(self.distance(a, b), a, b, self.1)
+    }
+}
+
+impl StringMetric for Normalized<OptimalStringAlignment> {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.osa_distance_with(b, self.1)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        normalize_edit_distance_with(self.distance(a, b), a, b, self.1)
+    }
+}
+
+impl StringMetric for Normalized<DamerauLevenshtein> {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.damerau_levenshtein_distance_with(b, self.1)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        normalize_edit_distance_with(self.distance(a, b), a, b, self.1)
+    }
+}
+
+impl StringMetric for Normalized<Hamming> {
+    type Distance = Option<usize>;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        a.hamming_distance_with(b, self.1)
+    }
+
+    fn distance_within(&self, a: &str, b: &str, max: Self::Distance) -> Option<Self::Distance> {
+        hamming_distance_within(self.distance(a, b), max)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        match self.distance(a, b) {
+            Some(distance) => normalize_edit_distance_with(distance, a, b, self.1),
+            None => 0.0,
+        }
+    }
+}
+
+impl StringMetric for Normalized<Jaro> {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        a.jaro_similarity_with(b, self.1)
+    }
+}
+
+impl StringMetric for Normalized<JaroWinkler> {
+    type Distance = f64;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        1.0 - self.normalized_similarity(a, b)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        let Normalized(metric, options) = self;
+        a.jaro_winkler_similarity_with(b, metric.prefix_scale, metric.boost_threshold, *options)
+            .min(1.0)
+    }
+}
+
+impl StringMetric for Normalized<LongestCommonSubsequence> {
+    type Distance = usize;
+
+    fn distance(&self, a: &str, b: &str) -> Self::Distance {
+        let (a_len, b_len) = (self.1.chars(a).count(), self.1.chars(b).count());
+        a_len + b_len - 2 * a.lcs_length_with(b, self.1)
+    }
+
+    fn normalized_similarity(&self, a: &str, b: &str) -> f64 {
+        let len = self.1.chars(a).count() + self.1.chars(b).count();
+
+        if len == 0 {
+            1.0
+        } else {
+            2.0 * a.lcs_length_with(b, self.1) as f64 / len as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ByUnit, Cosine, DamerauLevenshtein, Hamming, Jaccard, Jaro, JaroWinkler, Levenshtein,
+        LongestCommonSubsequence, LongestCommonSubstring, Normalized, OptimalStringAlignment,
+        Overlap, RatcliffObershelp, SorensenDice, StringMetric, WeightedLevenshtein,
+    };
+    use crate::{CaseInsensitiveCosts, CompareOptions, QGram, StrExt, UniformCosts, Unit};
+
+    const SEED: [(&str, &str); 11] = [
+        ("", ""),
+        ("", "a"),
+        ("a", ""),
+        ("abc", "def"),
+        ("teh", "the"),
+        ("kitten", "sitting"),
+        ("Kitten", "kitten"),
+        ("update", "udpate"),
+        ("cafexĀ", "cafeyȀ"),
+        ("lorem ipsum dolor", "ipsum"),
+        // "Ā" and "ab" are both the longest common substrings in bytes.
+        ("Ā-ab", "ab-Ā"),
+    ];
+
+    fn assert_normalized<M: StringMetric>(metric: M) {
+        assert_bounded(&metric);
+
+        for (a, b) in SEED {
+            assert_eq!(
+                metric.normalized_similarity(a, b),
+                metric.normalized_similarity(b, a),
+                "{a} {b}"
+            );
+        }
+    }
+
+    fn assert_bounded<M: StringMetric>(metric: &M) {
+        for (a, b) in SEED {
+            let similarity = metric.normalized_similarity(a, b);
+            assert!((0.0..=1.0).contains(&similarity), "{a} {b} {similarity}");
+            assert_eq!(metric.normalized_similarity(a, a), 1.0, "{a}");
+        }
+    }
+
+    #[test]
+    fn normalized_similarity() {
+        assert_normalized(Levenshtein);
+        assert_normalized(OptimalStringAlignment);
+        assert_normalized(DamerauLevenshtein);
+        assert_normalized(WeightedLevenshtein(UniformCosts));
+        assert_normalized(WeightedLevenshtein(CaseInsensitiveCosts::default()));
+        assert_normalized(Hamming);
+        assert_normalized(Jaro);
+        assert_normalized(JaroWinkler::default());
+        assert_normalized(LongestCommonSubstring);
+        assert_normalized(LongestCommonSubsequence);
+        // difflib's ratio depends on the order of the strings.
+        assert_bounded(&RatcliffObershelp);
+        assert_ne!(
+            RatcliffObershelp.normalized_similarity("abc", "cac"),
+            RatcliffObershelp.normalized_similarity("cac", "abc")
+        );
+        assert_normalized(Jaccard::default());
+        assert_normalized(SorensenDice(QGram::new(3).padded('#')));
+        assert_normalized(Cosine::default());
+        assert_normalized(Overlap(QGram::new(1)));
+        assert_normalized(Jaccard(QGram::new(2).by(Unit::Grapheme)));
+
+        for unit in [Unit::Byte, Unit::Char, Unit::Grapheme] {
+            assert_normalized(ByUnit(Levenshtein, unit));
+            assert_normalized(ByUnit(OptimalStringAlignment, unit));
+            assert_normalized(ByUnit(DamerauLevenshtein, unit));
+            assert_normalized(ByUnit(Hamming, unit));
+            assert_normalized(ByUnit(Jaro, unit));
+            assert_normalized(ByUnit(JaroWinkler::default(), unit));
+            assert_normalized(ByUnit(LongestCommonSubsequence, unit));
+        }
+
+        let options = CompareOptions::new()
+            .case_insensitive()
+            .accent_insensitive()
+            .collapse_whitespace()
+            .ignore_punctuation();
+        assert_normalized(Normalized(Levenshtein, options));
+        assert_normalized(Normalized(OptimalStringAlignment, options));
+        assert_normalized(Normalized(DamerauLevenshtein, options));
+        assert_normalized(Normalized(Hamming, options));
+        assert_normalized(Normalized(Jaro, options));
+        assert_normalized(Normalized(JaroWinkler::default(), options));
+        assert_normalized(Normalized(LongestCommonSubsequence, options));
+    }
+
+    #[test]
+    fn distance() {
+        for (a, b) in SEED {
+            assert_eq!(Levenshtein.distance(a, b), a.levenshtein_distance(b));
+            assert_eq!(OptimalStringAlignment.distance(a, b), a.osa_distance(b));
+            assert_eq!(
+                DamerauLevenshtein.distance(a, b),
+                a.damerau_levenshtein_distance(b)
+            );
+            assert_eq!(
+                WeightedLevenshtein(UniformCosts).distance(a, b),
+                a.levenshtein_distance(b) as f64
+            );
+            assert_eq!(Hamming.distance(a, b), a.hamming_distance(b));
+            assert_eq!(Jaro.distance(a, b), 1.0 - a.jaro_similarity(b));
+
+            for max in 0..4 {
+                assert_eq!(
+                    Levenshtein.distance_within(a, b, max),
+                    a.levenshtein_distance_within(b, max)
+                );
+                assert_eq!(
+                    OptimalStringAlignment.distance_within(a, b, max),
+                    Some(a.osa_distance(b)).filter(|&d| d <= max)
+                );
+                assert_eq!(
+                    Hamming.distance_within(a, b, Some(max)),
+                    a.hamming_distance(b).filter(|&d| d <= max).map(Some)
+                );
+            }
+            assert_eq!(
+                Jaccard::default().distance(a, b),
+                1.0 - a.jaccard_similarity(b, QGram::default())
+            );
+        }
+
+        assert_eq!(
+            Levenshtein.normalized_similarity("kitten", "sitting"),
+            1.0 - 3.0 / 7.0
+        );
+        assert_eq!(
+            Hamming.normalized_similarity("update", "udpate"),
+            1.0 - 2.0 / 6.0
+        );
+        assert_eq!(Hamming.normalized_similarity("ring", "bring"), 0.0);
+        assert_eq!(
+            WeightedLevenshtein(CaseInsensitiveCosts::default()).distance("Kitten", "kitten"),
+            0.0
+        );
+        assert_eq!(LongestCommonSubstring.distance("sparrow", "crow"), 5);
+        assert_eq!(
+            LongestCommonSubstring.normalized_similarity("sparrow", "crow"),
+            6.0 / 11.0
+        );
+        assert_eq!(LongestCommonSubsequence.distance("kitten", "sitting"), 5);
+        assert_eq!(
+            LongestCommonSubsequence.normalized_similarity("kitten", "sitting"),
+            8.0 / 13.0
+        );
+
+        // "é" as "e" followed by a combining acute accent.
+        let (a, b) = ("cafe\u{301}", "caf\u{e9}");
+        assert_eq!(ByUnit(Levenshtein, Unit::Grapheme).distance(a, b), 1);
+        assert_eq!(
+            ByUnit(Levenshtein, Unit::Grapheme).normalized_similarity(a, b),
+            0.75
+        );
+        assert_eq!(ByUnit(Hamming, Unit::Byte).distance(a, b), None);
+        assert_eq!(
+            ByUnit(LongestCommonSubsequence, Unit::Char).distance(a, b),
+            3
+        );
+        assert_eq!(
+            ByUnit(LongestCommonSubsequence, Unit::Grapheme).distance(a, b),
+            2
+        );
+    }
+}
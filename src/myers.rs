@@ -0,0 +1,229 @@
+//! Bit-parallel Levenshtein distance, as described by G. Myers in "A fast bit-vector algorithm
+//! for approximate string matching based on dynamic programming" (1999), using the formulation
+//! and the block extension by H. Hyyrö in "A bit-vector algorithm for computing Levenshtein and
+//! Damerau edit distances" (2003).
+//!
+//! Each column of the distance matrix is encoded as two bit-vectors holding the vertical
+//! positive and negative deltas between adjacent cells, so a whole column is computed
+//! with a handful of word operations.
+
+use std::collections::HashMap;
+
+use crate::unit::Symbol;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// For every unit of a pattern of at most 64 units, the bit-vector marking the positions where it occurs.
+/// Lives on the stack and only allocates when the pattern contains non-ASCII units.
+struct WordMasks<T> {
+    ascii: [u64; 128],
+    others: Vec<(T, u64)>,
+}
+
+impl<T: Symbol> WordMasks<T> {
+    fn new(pattern: impl Iterator<Item = T>) -> Self {
+        let mut ascii = [0; 128];
+        let mut others = Vec::<(T, u64)>::new();
+
+        for (index, c) in pattern.enumerate() {
+            let bit = 1 << index;
+
+            if let Some(b) = c.as_ascii() {
+                ascii[b as usize] |= bit;
+            } else if let Some((_, mask)) = others.iter_mut().find(|(o, _)| *o == c) {
+                *mask |= bit;
+            } else {
+                others.push((c, bit));
+            }
+        }
+
+        Self { ascii, others }
+    }
+
+    fn get(&self, c: T) -> u64 {
+        match c.as_ascii() {
+            Some(b) => self.ascii[b as usize],
+            None => self
+                .others
+                .iter()
+                .find(|(o, _)| *o == c)
+                .map_or(0, |(_, mask)| *mask),
+        }
+    }
+}
+
+/// For every unit of the pattern, the bit-vectors marking the positions where it occurs.
+struct PatternMasks<T> {
+    words: usize,
+    ascii: Vec<u64>,
+    others: HashMap<T, Vec<u64>>,
+}
+
+impl<T: Symbol> PatternMasks<T> {
+    fn new(pattern: impl Iterator<Item = T>, words: usize) -> Self {
+        let mut ascii = vec![0; 128 * words];
+        let mut others = HashMap::new();
+
+        for (index, c) in pattern.enumerate() {
+            let (word, bit) = (index / WORD_BITS, 1 << (index % WORD_BITS));
+
+            match c.as_ascii() {
+                Some(b) => ascii[b as usize * words + word] |= bit,
+                None => others.entry(c).or_insert_with(|| vec![0; words])[word] |= bit,
+            }
+        }
+
+        Self {
+            words,
+            ascii,
+            others,
+        }
+    }
+
+    fn get(&self, word: usize, c: T) -> u64 {
+        match c.as_ascii() {
+            Some(b) => self.ascii[b as usize * self.words + word],
+            None => self.others.get(&c).map_or(0, |masks| masks[word]),
+        }
+    }
+}
+
+/// Computes the Levenshtein distance between `text` and `pattern`,
+/// `pattern` must be made up of at least 1 and at most 64 units.
+pub(crate) fn distance<T: Symbol>(
+    text: impl Iterator<Item = T>,
+    pattern: impl Iterator<Item = T>,
+    pattern_len: usize,
+) -> usize {
+    debug_assert!(pattern_len > 0 && pattern_len <= WORD_BITS);
+
+    let masks = WordMasks::new(pattern);
+    let last = 1 << (pattern_len - 1);
+    let (mut vp, mut vn) = (!0u64, 0u64);
+    let mut distance = pattern_len;
+
+    for c in text {
+        let eq = masks.get(c);
+        let x = eq | vn;
+        let d0 = ((x & vp).wrapping_add(vp) ^ vp) | x;
+        let hp = vn | !(d0 | vp);
+        let hn = d0 & vp;
+
+        if hp & last != 0 {
+            distance += 1;
+        } else if hn & last != 0 {
+            distance -= 1;
+        }
+
+        // the first row of the matrix grows by one at each column.
+        let hp = (hp << 1) | 1;
+        let hn = hn << 1;
+
+        vp = hn | !(d0 | hp);
+        vn = hp & d0;
+    }
+
+    distance
+}
+
+/// Computes the Levenshtein distance between `text` and `pattern`,
+/// `pattern` must be made up of at least 1 unit and may be arbitrarily long.
+pub(crate) fn blocked_distance<T: Symbol>(
+    text: impl Iterator<Item = T>,
+    pattern: impl Iterator<Item = T>,
+    pattern_len: usize,
+) -> usize {
+    debug_assert!(pattern_len > 0);
+
+    let words = (pattern_len + WORD_BITS - 1) / WORD_BITS;
+    let masks = PatternMasks::new(pattern, words);
+    let last = 1 << ((pattern_len - 1) % WORD_BITS);
+    let mut vp = vec![!0u64; words];
+    let mut vn = vec![0u64; words];
+    let mut distance = pattern_len;
+
+    for c in text {
+        // the horizontal deltas flowing out of the bottom of each block into the next one,
+        // the first row of the matrix grows by one at each column.
+        let (mut hp_carry, mut hn_carry) = (1, 0);
+
+        for word in 0..words {
+            let eq = masks.get(word, c);
+            let x = eq | hn_carry;
+            let d0 = ((x & vp[word]).wrapping_add(vp[word]) ^ vp[word]) | x | vn[word];
+            let hp = vn[word] | !(d0 | vp[word]);
+            let hn = d0 & vp[word];
+
+            let (hp_carry_in, hn_carry_in) = (hp_carry, hn_carry);
+            if word + 1 < words {
+                hp_carry = hp >> (WORD_BITS - 1);
+                hn_carry = hn >> (WORD_BITS - 1);
+            } else {
+                hp_carry = (hp & last != 0) as u64;
+                hn_carry = (hn & last != 0) as u64;
+            }
+
+            let hp = (hp << 1) | hp_carry_in;
+            let hn = (hn << 1) | hn_carry_in;
+
+            vp[word] = hn | !(d0 | hp);
+            vn[word] = hp & d0;
+        }
+
+        distance += hp_carry as usize;
+        distance -= hn_carry as usize;
+    }
+
+    distance
+}
+
+/// The search of a pattern in a text read one unit at a time, as described by P. H. Sellers in
+/// "The theory and computation of evolutionary distances: pattern recognition" (1980).
+/// The first row of the matrix is all zeros, so that the substrings of the text may start anywhere.
+/// The pattern must be made up of at least 1 and at most 64 units.
+pub(crate) struct Search<T> {
+    masks: WordMasks<T>,
+    last: u64,
+    vp: u64,
+    vn: u64,
+    distance: usize,
+}
+
+impl<T: Symbol> Search<T> {
+    pub(crate) fn new(pattern: impl Iterator<Item = T>, pattern_len: usize) -> Self {
+        debug_assert!(pattern_len > 0 && pattern_len <= WORD_BITS);
+
+        Self {
+            masks: WordMasks::new(pattern),
+            last: 1 << (pattern_len - 1),
+            vp: !0,
+            vn: 0,
+            distance: pattern_len,
+        }
+    }
+
+    /// Reads `c` and returns the least Levenshtein distance between
+    /// the pattern and the substrings of the text ending with `c`.
+    pub(crate) fn step(&mut self, c: T) -> usize {
+        let (vp, vn) = (self.vp, self.vn);
+        let eq = self.masks.get(c);
+        let x = eq | vn;
+        let d0 = ((x & vp).wrapping_add(vp) ^ vp) | x;
+        let hp = vn | !(d0 | vp);
+        let hn = d0 & vp;
+
+        if hp & self.last != 0 {
+            self.distance += 1;
+        } else if hn & self.last != 0 {
+            self.distance -= 1;
+        }
+
+        // unlike in `distance`, the first row does not grow.
+        let hp = hp << 1;
+        let hn = hn << 1;
+
+        self.vp = hn | !(d0 | hp);
+        self.vn = hp & d0;
+        self.distance
+    }
+}
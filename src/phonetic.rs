@@ -0,0 +1,903 @@
+//! Phonetic encodings, turning a word into a code that is shared by words
+//! that sound alike in English, regardless of their spelling.
+//!
+//! Only the ASCII letters of the words are taken into account, except for Double Metaphone
+//! that also understands `Ç` and `Ñ` and uses spaces to recognize some name prefixes.
+
+/// Returns the uppercase ASCII letters of `s`.
+fn letters(s: &str) -> impl Iterator<Item = u8> + '_ {
+    s.bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|b| b.to_ascii_uppercase())
+}
+
+fn is_vowel(b: u8) -> bool {
+    matches!(b, b'A' | b'E' | b'I' | b'O' | b'U')
+}
+
+/// American Soundex, as described by the U.S. National Archives.
+pub(crate) fn soundex(s: &str) -> String {
+    fn digit(b: u8) -> Option<u8> {
+        match b {
+            b'B' | b'F' | b'P' | b'V' => Some(b'1'),
+            b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => Some(b'2'),
+            b'D' | b'T' => Some(b'3'),
+            b'L' => Some(b'4'),
+            b'M' | b'N' => Some(b'5'),
+            b'R' => Some(b'6'),
+            _ => None,
+        }
+    }
+
+    let mut letters = letters(s);
+    let first = match letters.next() {
+        Some(first) => first,
+        None => return String::new(),
+    };
+
+    let mut code = String::with_capacity(4);
+    code.push(first as char);
+
+    let mut last = digit(first);
+    for b in letters {
+        let digit = digit(b);
+
+        if digit.is_some() && digit != last {
+            code.extend(digit.map(char::from));
+
+            if code.len() == 4 {
+                break;
+            }
+        }
+
+        // letters with the same digit separated by 'H' or 'W' are coded once,
+        // while vowels separate them.
+        if b != b'H' && b != b'W' {
+            last = digit;
+        }
+    }
+
+    while code.len() < 4 {
+        code.push('0');
+    }
+
+    code
+}
+
+/// Refined Soundex, as implemented by Apache Commons Codec.
+pub(crate) fn refined_soundex(s: &str) -> String {
+    const DIGITS: &[u8; 26] = b"01360240043788015936020505";
+
+    let mut letters = letters(s).peekable();
+    let mut code = String::new();
+
+    if let Some(&first) = letters.peek() {
+        code.push(first as char);
+    }
+
+    let mut last = None;
+    for b in letters {
+        let digit = DIGITS[(b - b'A') as usize];
+
+        if last != Some(digit) {
+            code.push(digit as char);
+        }
+
+        last = Some(digit);
+    }
+
+    code
+}
+
+/// Metaphone, as described by L. Philips in "Hanging on the Metaphone" (1990).
+pub(crate) fn metaphone(s: &str) -> String {
+    let mut word = Vec::<u8>::new();
+    for b in letters(s) {
+        // adjacent duplicate letters are coded once, except for 'C'.
+        if word.last() != Some(&b) || b == b'C' {
+            word.push(b);
+        }
+    }
+
+    match word.as_slice() {
+        [b'K' | b'G' | b'P', b'N', ..] | [b'A', b'E', ..] | [b'W', b'R', ..] => {
+            word.remove(0);
+        }
+        [b'W', b'H', ..] => {
+            word.remove(1);
+        }
+        [b'X', ..] => word[0] = b'S',
+        _ => {}
+    }
+
+    let at = |i: usize| word.get(i).copied().unwrap_or_default();
+    let mut code = String::new();
+
+    for (i, &b) in word.iter().enumerate() {
+        let (previous, next, after) = (at(i.wrapping_sub(1)), at(i + 1), at(i + 2));
+        let soft = matches!(next, b'E' | b'I' | b'Y');
+
+        match b {
+            b'A' | b'E' | b'I' | b'O' | b'U' if i == 0 => code.push(b as char),
+            b'A' | b'E' | b'I' | b'O' | b'U' => {}
+            // silent in "-MB" at the end.
+            b'B' if previous == b'M' && i + 1 == word.len() => {}
+            b'C' if next == b'I' && after == b'A' => code.push('X'),
+            b'C' if next == b'H' && previous == b'S' => code.push('K'),
+            b'C' if next == b'H' => code.push('X'),
+            // silent in "-SCI-", "-SCE-" and "-SCY-".
+            b'C' if soft && previous == b'S' => {}
+            b'C' if soft => code.push('S'),
+            b'C' => code.push('K'),
+            b'D' if next == b'G' && matches!(after, b'E' | b'I' | b'Y') => code.push('J'),
+            b'D' => code.push('T'),
+            // silent in "-GH-" when not at the end nor before a vowel.
+            b'G' if next == b'H' && i + 2 < word.len() && !is_vowel(after) => {}
+            // silent in "-GN" and "-GNED" at the end.
+            b'G' if word[i..] == *b"GN" || word[i..] == *b"GNED" => {}
+            // already coded in "-DGE-", "-DGI-" and "-DGY-".
+            b'G' if soft && previous == b'D' => {}
+            b'G' if soft && previous != b'G' => code.push('J'),
+            b'G' => code.push('K'),
+            b'H' if matches!(previous, b'C' | b'S' | b'P' | b'T' | b'G') => {}
+            b'H' if is_vowel(next) => code.push('H'),
+            b'H' => {}
+            b'K' if previous == b'C' => {}
+            b'P' if next == b'H' => code.push('F'),
+            b'Q' => code.push('K'),
+            b'S' if next == b'H' || (next == b'I' && matches!(after, b'O' | b'A')) => {
+                code.push('X')
+            }
+            b'T' if next == b'I' && matches!(after, b'O' | b'A') => code.push('X'),
+            b'T' if next == b'H' => code.push('0'),
+            // silent in "-TCH-".
+            b'T' if next == b'C' && after == b'H' => {}
+            b'V' => code.push('F'),
+            b'W' | b'Y' if !is_vowel(next) => {}
+            b'X' => code.push_str("KS"),
+            b'Z' => code.push('S'),
+            _ => code.push(b as char),
+        }
+    }
+
+    code
+}
+
+/// New York State Identification and Intelligence System phonetic code, as described by
+/// R. L. Taft in "Name Search Techniques" (1970), truncated to 6 letters.
+pub(crate) fn nysiis(s: &str) -> String {
+    let mut word = letters(s).collect::<Vec<_>>();
+
+    // translate the first letters.
+    for (from, to) in [
+        (&b"MAC"[..], &b"MCC"[..]),
+        (b"KN", b"NN"),
+        (b"K", b"C"),
+        (b"PH", b"FF"),
+        (b"PF", b"FF"),
+        (b"SCH", b"SSS"),
+    ] {
+        if word.starts_with(from) {
+            word[..to.len()].copy_from_slice(to);
+            break;
+        }
+    }
+
+    // translate the last letters.
+    for (from, to) in [
+        (&b"EE"[..], b'Y'),
+        (b"IE", b'Y'),
+        (b"DT", b'D'),
+        (b"RT", b'D'),
+        (b"RD", b'D'),
+        (b"NT", b'D'),
+        (b"ND", b'D'),
+    ] {
+        if word.ends_with(from) {
+            word.truncate(word.len() - from.len());
+            word.push(to);
+            break;
+        }
+    }
+
+    let first = match word.first() {
+        Some(&first) => first,
+        None => return String::new(),
+    };
+
+    let mut code = vec![first];
+
+    // each letter is translated considering the already translated previous one.
+    for i in 1..word.len() {
+        let at = |i: usize| word.get(i).copied().unwrap_or_default();
+        let (previous, current, next, after) = (word[i - 1], word[i], at(i + 1), at(i + 2));
+
+        let translation: &[u8] = match current {
+            b'E' if next == b'V' => b"AF",
+            b'A' | b'E' | b'I' | b'O' | b'U' => b"A",
+            b'Q' => b"G",
+            b'Z' => b"S",
+            b'M' => b"N",
+            b'K' if next == b'N' => b"NN",
+            b'K' => b"C",
+            b'S' if next == b'C' && after == b'H' => b"SSS",
+            b'P' if next == b'H' => b"FF",
+            b'H' if !is_vowel(previous) || !is_vowel(next) => &[previous],
+            b'W' if is_vowel(previous) => &[previous],
+            _ => &[current],
+        };
+
+        let end = usize::min(i + translation.len(), word.len());
+        word[i..end].copy_from_slice(&translation[..end - i]);
+
+        if word[i] != word[i - 1] {
+            code.push(word[i]);
+        }
+    }
+
+    if code.len() > 1 && code.last() == Some(&b'S') {
+        code.pop();
+    }
+
+    if code.len() > 2 && code.ends_with(b"AY") {
+        code.remove(code.len() - 2);
+    }
+
+    if code.len() > 1 && code.last() == Some(&b'A') {
+        code.pop();
+    }
+
+    code.truncate(6);
+    code.into_iter().map(char::from).collect()
+}
+
+/// Double Metaphone, as described by L. Philips in "The Double Metaphone Search Algorithm"
+/// (2000), following the implementation of Apache Commons Codec.
+/// Returns the primary and the alternate codes, both of at most 4 letters.
+pub(crate) fn double_metaphone(s: &str) -> (String, String) {
+    DoubleMetaphone::new(s).encode()
+}
+
+struct DoubleMetaphone {
+    word: Vec<char>,
+    slavo_germanic: bool,
+    primary: String,
+    alternate: String,
+}
+
+impl DoubleMetaphone {
+    const MAX_LEN: usize = 4;
+
+    fn new(s: &str) -> Self {
+        let word = s
+            .trim()
+            .chars()
+            .flat_map(char::to_uppercase)
+            .collect::<Vec<_>>();
+
+        let slavo_germanic =
+            word.contains(&'W') || word.contains(&'K') || word.windows(2).any(|w| w == ['C', 'Z']);
+
+        Self {
+            word,
+            slavo_germanic,
+            primary: String::with_capacity(Self::MAX_LEN),
+            alternate: String::with_capacity(Self::MAX_LEN),
+        }
+    }
+
+    fn len(&self) -> isize {
+        self.word.len() as isize
+    }
+
+    /// Returns the char at `i`, or `'\0'` if `i` is out of bounds.
+    fn at(&self, i: isize) -> char {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| self.word.get(i))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn is_vowel_at(&self, i: isize) -> bool {
+        matches!(self.at(i), 'A' | 'E' | 'I' | 'O' | 'U' | 'Y')
+    }
+
+    /// Returns `true` if any of `options`, all of the same length, is found at `start`.
+    fn matches(&self, start: isize, options: &[&str]) -> bool {
+        let len = options[0].len() as isize;
+
+        if start < 0 || start + len > self.len() {
+            return false;
+        }
+
+        let window = &self.word[start as usize..(start + len) as usize];
+        options
+            .iter()
+            .any(|option| option.chars().eq(window.iter().copied()))
+    }
+
+    fn push_primary(&mut self, code: &str) {
+        for c in code.chars() {
+            if self.primary.len() < Self::MAX_LEN {
+                self.primary.push(c);
+            }
+        }
+    }
+
+    fn push_alternate(&mut self, code: &str) {
+        for c in code.chars() {
+            if self.alternate.len() < Self::MAX_LEN {
+                self.alternate.push(c);
+            }
+        }
+    }
+
+    fn push(&mut self, primary: &str, alternate: &str) {
+        self.push_primary(primary);
+        self.push_alternate(alternate);
+    }
+
+    fn push_both(&mut self, code: &str) {
+        self.push(code, code);
+    }
+
+    /// Codes `code` and skips the next char if it is the same as the one at `i`.
+    fn push_skipping_double(&mut self, i: isize, code: &str) -> isize {
+        self.push_both(code);
+
+        if self.at(i + 1) == self.at(i) {
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+
+    fn encode(mut self) -> (String, String) {
+        let mut i = if self.matches(0, &["GN", "KN", "PN", "WR", "PS"]) {
+            1
+        } else {
+            0
+        };
+
+        while (self.primary.len() < Self::MAX_LEN || self.alternate.len() < Self::MAX_LEN)
+            && i < self.len()
+        {
+            i = match self.at(i) {
+                'A' | 'E' | 'I' | 'O' | 'U' | 'Y' => {
+                    if i == 0 {
+                        self.push_both("A");
+                    }
+                    i + 1
+                }
+                'B' => self.push_skipping_double(i, "P"),
+                'Ç' => {
+                    self.push_both("S");
+                    i + 1
+                }
+                'C' => self.c(i),
+                'D' => self.d(i),
+                'F' => self.push_skipping_double(i, "F"),
+                'G' => self.g(i),
+                'H' => self.h(i),
+                'J' => self.j(i),
+                'K' => self.push_skipping_double(i, "K"),
+                'L' => self.l(i),
+                'M' => self.m(i),
+                'N' => self.push_skipping_double(i, "N"),
+                'Ñ' => {
+                    self.push_both("N");
+                    i + 1
+                }
+                'P' => self.p(i),
+                'Q' => self.push_skipping_double(i, "K"),
+                'R' => self.r(i),
+                'S' => self.s(i),
+                'T' => self.t(i),
+                'V' => self.push_skipping_double(i, "F"),
+                'W' => self.w(i),
+                'X' => self.x(i),
+                'Z' => self.z(i),
+                _ => i + 1,
+            };
+        }
+
+        (self.primary, self.alternate)
+    }
+
+    /// Returns `true` if the word has a germanic "VAN " or "VON " prefix or starts with "SCH".
+    fn is_germanic(&self) -> bool {
+        self.matches(0, &["VAN ", "VON "]) || self.matches(0, &["SCH"])
+    }
+
+    fn c(&mut self, i: isize) -> isize {
+        if self.is_germanic_ch(i) {
+            self.push_both("K");
+            i + 2
+        } else if i == 0 && self.matches(i, &["CAESAR"]) {
+            self.push_both("S");
+            i + 2
+        } else if self.matches(i, &["CH"]) {
+            self.ch(i)
+        } else if self.matches(i, &["CZ"]) && !self.matches(i - 2, &["WICZ"]) {
+            // "Czerny".
+            self.push("S", "X");
+            i + 2
+        } else if self.matches(i + 1, &["CIA"]) {
+            // "focaccia".
+            self.push_both("X");
+            i + 3
+        } else if self.matches(i, &["CC"]) && !(i == 1 && self.at(0) == 'M') {
+            // double "CC", but not "McClelland".
+            self.cc(i)
+        } else if self.matches(i, &["CK", "CG", "CQ"]) {
+            self.push_both("K");
+            i + 2
+        } else if self.matches(i, &["CI", "CE", "CY"]) {
+            // italian vs. english.
+            if self.matches(i, &["CIO", "CIE", "CIA"]) {
+                self.push("S", "X");
+            } else {
+                self.push_both("S");
+            }
+            i + 2
+        } else {
+            self.push_both("K");
+
+            if self.matches(i + 1, &[" C", " Q", " G"]) {
+                // "Mac Caffrey", "Mac Gregor".
+                i + 3
+            } else if self.matches(i + 1, &["C", "K", "Q"]) && !self.matches(i + 1, &["CE", "CI"]) {
+                i + 2
+            } else {
+                i + 1
+            }
+        }
+    }
+
+    /// Returns `true` for the "CH" sounding like "K" in germanic words such as "Bacher".
+    fn is_germanic_ch(&self, i: isize) -> bool {
+        if self.matches(i, &["CHIA"]) {
+            true
+        } else if i <= 1 || self.is_vowel_at(i - 2) || !self.matches(i - 1, &["ACH"]) {
+            false
+        } else {
+            !matches!(self.at(i + 2), 'I' | 'E') || self.matches(i - 2, &["BACHER", "MACHER"])
+        }
+    }
+
+    fn cc(&mut self, i: isize) -> isize {
+        if self.matches(i + 2, &["I", "E", "H"]) && !self.matches(i + 2, &["HU"]) {
+            // "bellocchio", but not "bacchus".
+            if (i == 1 && self.at(i - 1) == 'A') || self.matches(i - 1, &["UCCEE", "UCCES"]) {
+                // "accident", "accede", "succeed".
+                self.push_both("KS");
+            } else {
+                // "bacci", "bertucci", other italian words.
+                self.push_both("X");
+            }
+            i + 3
+        } else {
+            // Pierce's rule.
+            self.push_both("K");
+            i + 2
+        }
+    }
+
+    fn ch(&mut self, i: isize) -> isize {
+        if i > 0 && self.matches(i, &["CHAE"]) {
+            // "Michael".
+            self.push("K", "X");
+        } else if i == 0
+            && (self.matches(i + 1, &["HARAC", "HARIS"])
+                || self.matches(i + 1, &["HOR", "HYM", "HIA", "HEM"]))
+            && !self.matches(0, &["CHORE"])
+        {
+            // greek roots, "chemistry", "chorus".
+            self.push_both("K");
+        } else if self.is_germanic()
+            || self.matches(i - 2, &["ORCHES", "ARCHIT", "ORCHID"])
+            || self.matches(i + 2, &["T", "S"])
+            || ((i == 0 || self.matches(i - 1, &["A", "O", "U", "E"]))
+                && (self.matches(i + 2, &["L", "R", "N", "M", "B", "H", "F", "V", "W", " "])
+                    || i + 1 == self.len() - 1))
+        {
+            // germanic, greek, or otherwise "CH" for "KH" sound.
+            self.push_both("K");
+        } else if i == 0 {
+            self.push_both("X");
+        } else if self.matches(0, &["MC"]) {
+            self.push_both("K");
+        } else {
+            self.push("X", "K");
+        }
+
+        i + 2
+    }
+
+    fn d(&mut self, i: isize) -> isize {
+        if self.matches(i, &["DG"]) {
+            if self.matches(i + 2, &["I", "E", "Y"]) {
+                // "edge".
+                self.push_both("J");
+                i + 3
+            } else {
+                // "Edgar".
+                self.push_both("TK");
+                i + 2
+            }
+        } else if self.matches(i, &["DT", "DD"]) {
+            self.push_both("T");
+            i + 2
+        } else {
+            self.push_both("T");
+            i + 1
+        }
+    }
+
+    fn g(&mut self, i: isize) -> isize {
+        if self.at(i + 1) == 'H' {
+            self.gh(i)
+        } else if self.at(i + 1) == 'N' {
+            if i == 1 && self.is_vowel_at(0) && !self.slavo_germanic {
+                self.push("KN", "N");
+            } else if !self.matches(i + 2, &["EY"]) && self.at(i + 1) != 'Y' && !self.slavo_germanic
+            {
+                self.push("N", "KN");
+            } else {
+                self.push_both("KN");
+            }
+            i + 2
+        } else if self.matches(i + 1, &["LI"]) && !self.slavo_germanic {
+            // "Tagliaro".
+            self.push("KL", "L");
+            i + 2
+        } else if i == 0
+            && (self.at(i + 1) == 'Y'
+                || self.matches(
+                    i + 1,
+                    &[
+                        "ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER",
+                    ],
+                ))
+        {
+            // "-GES-", "-GEP-", "-GEL-", "-GIE-" at the beginning.
+            self.push("K", "J");
+            i + 2
+        } else if (self.matches(i + 1, &["ER"]) || self.at(i + 1) == 'Y')
+            && !self.matches(0, &["DANGER", "RANGER", "MANGER"])
+            && !self.matches(i - 1, &["E", "I"])
+            && !self.matches(i - 1, &["RGY", "OGY"])
+        {
+            // "-GER-", "-GY-".
+            self.push("K", "J");
+            i + 2
+        } else if self.matches(i + 1, &["E", "I", "Y"]) || self.matches(i - 1, &["AGGI", "OGGI"]) {
+            // italian "biaggi".
+            if self.is_germanic() || self.matches(i + 1, &["ET"]) {
+                self.push_both("K");
+            } else if self.matches(i + 1, &["IER"]) {
+                self.push_both("J");
+            } else {
+                self.push("J", "K");
+            }
+            i + 2
+        } else {
+            self.push_skipping_double(i, "K")
+        }
+    }
+
+    fn gh(&mut self, i: isize) -> isize {
+        if i > 0 && !self.is_vowel_at(i - 1) {
+            self.push_both("K");
+        } else if i == 0 {
+            // "ghislane", "ghiradelli".
+            if self.at(i + 2) == 'I' {
+                self.push_both("J");
+            } else {
+                self.push_both("K");
+            }
+        } else if (i > 1 && self.matches(i - 2, &["B", "H", "D"]))
+            || (i > 2 && self.matches(i - 3, &["B", "H", "D"]))
+            || (i > 3 && self.matches(i - 4, &["B", "H"]))
+        {
+            // Parker's rule, "Hugh".
+        } else if i > 2 && self.at(i - 1) == 'U' && self.matches(i - 3, &["C", "G", "L", "R", "T"])
+        {
+            // "laugh", "McLaughlin", "cough", "gough", "rough", "tough".
+            self.push_both("F");
+        } else if i > 0 && self.at(i - 1) != 'I' {
+            self.push_both("K");
+        }
+
+        i + 2
+    }
+
+    fn h(&mut self, i: isize) -> isize {
+        // only kept if first and before a vowel or between two vowels.
+        if (i == 0 || self.is_vowel_at(i - 1)) && self.is_vowel_at(i + 1) {
+            self.push_both("H");
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+
+    fn j(&mut self, i: isize) -> isize {
+        if self.matches(i, &["JOSE"]) || self.matches(0, &["SAN "]) {
+            // obvious spanish, "Jose", "San Jacinto".
+            if (i == 0 && (self.at(i + 4) == ' ' || self.len() == 4)) || self.matches(0, &["SAN "])
+            {
+                self.push_both("H");
+            } else {
+                self.push("J", "H");
+            }
+            return i + 1;
+        }
+
+        if i == 0 {
+            // "Yankelovich", "Jankelowicz".
+            self.push("J", "A");
+        } else if self.is_vowel_at(i - 1)
+            && !self.slavo_germanic
+            && matches!(self.at(i + 1), 'A' | 'O')
+        {
+            // spanish pronunciation of "bajador".
+            self.push("J", "H");
+        } else if i == self.len() - 1 {
+            self.push_primary("J");
+        } else if !self.matches(i + 1, &["L", "T", "K", "S", "N", "M", "B", "Z"])
+            && !self.matches(i - 1, &["S", "K", "L"])
+        {
+            self.push_both("J");
+        }
+
+        if self.at(i + 1) == 'J' {
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+
+    fn l(&mut self, i: isize) -> isize {
+        if self.at(i + 1) != 'L' {
+            self.push_both("L");
+            return i + 1;
+        }
+
+        // spanish "cabrillo", "gallegos".
+        let spanish = (i == self.len() - 3 && self.matches(i - 1, &["ILLO", "ILLA", "ALLE"]))
+            || ((self.matches(self.len() - 2, &["AS", "OS"])
+                || self.matches(self.len() - 1, &["A", "O"]))
+                && self.matches(i - 1, &["ALLE"]));
+
+        if spanish {
+            self.push_primary("L");
+        } else {
+            self.push_both("L");
+        }
+
+        i + 2
+    }
+
+    fn m(&mut self, i: isize) -> isize {
+        self.push_both("M");
+
+        // "dumb", "thumb".
+        if self.at(i + 1) == 'M'
+            || (self.matches(i - 1, &["UMB"])
+                && (i + 1 == self.len() - 1 || self.matches(i + 2, &["ER"])))
+        {
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+
+    fn p(&mut self, i: isize) -> isize {
+        if self.at(i + 1) == 'H' {
+            self.push_both("F");
+            i + 2
+        } else {
+            // "Campbell", "raspberry".
+            self.push_both("P");
+
+            if self.matches(i + 1, &["P", "B"]) {
+                i + 2
+            } else {
+                i + 1
+            }
+        }
+    }
+
+    fn r(&mut self, i: isize) -> isize {
+        // french "Rogier", but not "Hochmeier".
+        if i == self.len() - 1
+            && !self.slavo_germanic
+            && self.matches(i - 2, &["IE"])
+            && !self.matches(i - 4, &["ME", "MA"])
+        {
+            self.push_alternate("R");
+        } else {
+            self.push_both("R");
+        }
+
+        if self.at(i + 1) == 'R' {
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+
+    fn s(&mut self, i: isize) -> isize {
+        if self.matches(i - 1, &["ISL", "YSL"]) {
+            // "island", "isle", "carlisle", "carlysle".
+            i + 1
+        } else if i == 0 && self.matches(i, &["SUGAR"]) {
+            self.push("X", "S");
+            i + 1
+        } else if self.matches(i, &["SH"]) {
+            if self.matches(i + 1, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
+                // germanic.
+                self.push_both("S");
+            } else {
+                self.push_both("X");
+            }
+            i + 2
+        } else if self.matches(i, &["SIO", "SIA"]) {
+            // italian and armenian.
+            if self.slavo_germanic {
+                self.push_both("S");
+            } else {
+                self.push("S", "X");
+            }
+            i + 3
+        } else if (i == 0 && self.matches(i + 1, &["M", "N", "L", "W"]))
+            || self.matches(i + 1, &["Z"])
+        {
+            // german and anglicisations, "Smith" matches "Schmidt", "Snider" matches "Schneider",
+            // also "-SZ-" in slavic languages, although in hungarian it sounds like "S".
+            self.push("S", "X");
+
+            if self.matches(i + 1, &["Z"]) {
+                i + 2
+            } else {
+                i + 1
+            }
+        } else if self.matches(i, &["SC"]) {
+            self.sc(i)
+        } else {
+            if i == self.len() - 1 && self.matches(i - 2, &["AI", "OI"]) {
+                // french "resnais", "artois".
+                self.push_alternate("S");
+            } else {
+                self.push_both("S");
+            }
+
+            if self.matches(i + 1, &["S", "Z"]) {
+                i + 2
+            } else {
+                i + 1
+            }
+        }
+    }
+
+    fn sc(&mut self, i: isize) -> isize {
+        if self.at(i + 2) == 'H' {
+            // Schlesinger's rule.
+            if self.matches(i + 3, &["OO", "ER", "EN", "UY", "ED", "EM"]) {
+                // dutch origin, "school", "schooner", "schermerhorn", "schenker".
+                if self.matches(i + 3, &["ER", "EN"]) {
+                    self.push("X", "SK");
+                } else {
+                    self.push_both("SK");
+                }
+            } else if i == 0 && !self.is_vowel_at(3) && self.at(3) != 'W' {
+                self.push("X", "S");
+            } else {
+                self.push_both("X");
+            }
+        } else if self.matches(i + 2, &["I", "E", "Y"]) {
+            self.push_both("S");
+        } else {
+            self.push_both("SK");
+        }
+
+        i + 3
+    }
+
+    fn t(&mut self, i: isize) -> isize {
+        if self.matches(i, &["TION"]) || self.matches(i, &["TIA", "TCH"]) {
+            self.push_both("X");
+            i + 3
+        } else if self.matches(i, &["TH"]) || self.matches(i, &["TTH"]) {
+            // "Thomas", "Thames" or germanic.
+            if self.matches(i + 2, &["OM", "AM"]) || self.is_germanic() {
+                self.push_both("T");
+            } else {
+                self.push("0", "T");
+            }
+            i + 2
+        } else {
+            self.push_both("T");
+
+            if self.matches(i + 1, &["T", "D"]) {
+                i + 2
+            } else {
+                i + 1
+            }
+        }
+    }
+
+    fn w(&mut self, i: isize) -> isize {
+        if self.matches(i, &["WR"]) {
+            // may be in the middle of the word too.
+            self.push_both("R");
+            i + 2
+        } else if i == 0 && self.is_vowel_at(i + 1) {
+            // "Wasserman" matches "Vasserman".
+            self.push("A", "F");
+            i + 1
+        } else if i == 0 && self.matches(i, &["WH"]) {
+            // "Uomo" matches "Womo".
+            self.push_both("A");
+            i + 1
+        } else if (i == self.len() - 1 && self.is_vowel_at(i - 1))
+            || self.matches(i - 1, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])
+            || self.matches(0, &["SCH"])
+        {
+            // "Arnow" matches "Arnoff".
+            self.push_alternate("F");
+            i + 1
+        } else if self.matches(i, &["WICZ", "WITZ"]) {
+            // polish, "Filipowicz".
+            self.push("TS", "FX");
+            i + 4
+        } else {
+            i + 1
+        }
+    }
+
+    fn x(&mut self, i: isize) -> isize {
+        if i == 0 {
+            self.push_both("S");
+            return i + 1;
+        }
+
+        // silent in french words such as "breaux".
+        let silent = i == self.len() - 1
+            && (self.matches(i - 3, &["IAU", "EAU"]) || self.matches(i - 2, &["AU", "OU"]));
+
+        if !silent {
+            self.push_both("KS");
+        }
+
+        if self.matches(i + 1, &["C", "X"]) {
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+
+    fn z(&mut self, i: isize) -> isize {
+        if self.at(i + 1) == 'H' {
+            // chinese pinyin, "Zhao".
+            self.push_both("J");
+            return i + 2;
+        }
+
+        if self.matches(i + 1, &["ZO", "ZI", "ZA"])
+            || (self.slavo_germanic && i > 0 && self.at(i - 1) != 'T')
+        {
+            self.push("S", "TS");
+        } else {
+            self.push_both("S");
+        }
+
+        if self.at(i + 1) == 'Z' {
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+}
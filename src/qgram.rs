@@ -0,0 +1,173 @@
+//! Similarities between the q-gram profiles of strings, that is the multisets of their
+//! substrings of `q` consecutive units.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::sailed::HzMap;
+use crate::Unit;
+
+/// The configuration of the q-grams, the substrings of `q` consecutive units (chars by default),
+/// in which strings are split by the q-gram based similarities.
+///
+/// When a `padding` char is set, each string is extended with `q - 1` padding chars on both
+/// edges before being split, so that the units at the edges appear in as many q-grams as the
+/// others and strings shorter than `q` units still have q-grams. Otherwise strings shorter
+/// than `q` units have no q-grams at all. When splitting in bytes, the padding char should
+/// be ASCII in order to span a single unit.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{QGram, StrExt, Unit};
+///
+/// let bigrams = QGram::new(2);
+/// assert_eq!("night".jaccard_similarity("nacht", bigrams), 1.0 / 7.0);
+///
+/// let padded = QGram::new(2).padded('#');
+/// assert_eq!("night".jaccard_similarity("nacht", padded), 3.0 / 9.0);
+///
+/// // "é" as "e" followed by a combining acute accent, a single grapheme.
+/// let (a, b) = ("cafe\u{301}", "caf\u{e9}");
+/// assert_eq!(a.jaccard_similarity(b, QGram::new(1)), 3.0 / 6.0);
+/// assert_eq!(a.jaccard_similarity(b, QGram::new(1).by(Unit::Grapheme)), 3.0 / 5.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QGram {
+    q: usize,
+    /// The char padding the strings at both edges, if any.
+    pub padding: Option<char>,
+    /// The unit in which strings are split.
+    pub unit: Unit,
+}
+
+impl QGram {
+    /// Creates a configuration for q-grams of `q` chars with no padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q` is `0`.
+    pub const fn new(q: usize) -> Self {
+        assert!(q > 0, "q-grams must be made up of at least 1 unit");
+
+        Self {
+            q,
+            padding: None,
+            unit: Unit::Char,
+        }
+    }
+
+    /// Returns the number of units of each q-gram, at least `1`.
+    pub const fn q(&self) -> usize {
+        self.q
+    }
+
+    /// Returns the configuration padding the strings with `padding` at both edges.
+    pub const fn padded(self, padding: char) -> Self {
+        Self {
+            padding: Some(padding),
+            ..self
+        }
+    }
+
+    /// Returns the configuration splitting the strings in q-grams of `q` units of `unit`.
+    pub const fn by(self, unit: Unit) -> Self {
+        Self { unit, ..self }
+    }
+
+    /// Returns `s` padded at both edges according to the configuration.
+    pub(crate) fn pad<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match self.padding {
+            Some(padding) if self.q > 1 => {
+                let edge = padding.to_string().repeat(self.q - 1);
+                Cow::Owned([edge.as_str(), s, edge.as_str()].concat())
+            }
+            _ => Cow::Borrowed(s),
+        }
+    }
+}
+
+impl Default for QGram {
+    /// Bigrams with no padding.
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+/// Returns an iterator over the bytes of the q-grams of `s`, in order of appearance.
+/// The q-grams of bytes may not be valid UTF-8, as they may split chars.
+pub(crate) fn qgrams(s: &str, qgram: QGram) -> impl Iterator<Item = &[u8]> {
+    let boundaries = qgram.unit.boundaries(s);
+    let count = boundaries.len().saturating_sub(qgram.q);
+
+    (0..count).map(move |i| &s.as_bytes()[boundaries[i]..boundaries[i + qgram.q]])
+}
+
+/// Counts the occurrences of each item yielded by `items`.
+pub(crate) fn frequencies<K, M: HzMap<K>>(items: impl Iterator<Item = K>) -> M {
+    let mut map = M::default();
+    items.for_each(|item| map.incr(item));
+    map
+}
+
+/// Computes a similarity between the q-gram profiles of `a` and `b` by means of `similarity`,
+/// falling back to comparing the strings when `similarity` is undefined (returns `None`)
+/// because of empty profiles.
+fn profile_similarity(
+    a: &str,
+    b: &str,
+    qgram: QGram,
+    similarity: impl FnOnce(&HashMap<&[u8], usize>, &HashMap<&[u8], usize>) -> Option<f64>,
+) -> f64 {
+    let (padded_a, padded_b) = (qgram.pad(a), qgram.pad(b));
+    let profile_a: HashMap<_, _> = frequencies(qgrams(&padded_a, qgram));
+    let profile_b: HashMap<_, _> = frequencies(qgrams(&padded_b, qgram));
+
+    similarity(&profile_a, &profile_b).unwrap_or(if a == b { 1.0 } else { 0.0 })
+}
+
+/// Returns the number of distinct q-grams that the profiles have in common.
+fn common(a: &HashMap<&[u8], usize>, b: &HashMap<&[u8], usize>) -> usize {
+    a.keys().filter(|gram| b.contains_key(*gram)).count()
+}
+
+/// Returns `numerator / denominator`, or `None` if `denominator` is zero.
+fn ratio(numerator: f64, denominator: f64) -> Option<f64> {
+    if denominator > 0.0 {
+        Some(numerator / denominator)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn jaccard_similarity(a: &str, b: &str, qgram: QGram) -> f64 {
+    profile_similarity(a, b, qgram, |a, b| {
+        let common = common(a, b);
+        ratio(common as f64, (a.len() + b.len() - common) as f64)
+    })
+}
+
+pub(crate) fn sorensen_dice_similarity(a: &str, b: &str, qgram: QGram) -> f64 {
+    profile_similarity(a, b, qgram, |a, b| {
+        ratio(2.0 * common(a, b) as f64, (a.len() + b.len()) as f64)
+    })
+}
+
+pub(crate) fn overlap_coefficient(a: &str, b: &str, qgram: QGram) -> f64 {
+    profile_similarity(a, b, qgram, |a, b| {
+        ratio(common(a, b) as f64, usize::min(a.len(), b.len()) as f64)
+    })
+}
+
+pub(crate) fn cosine_similarity(a: &str, b: &str, qgram: QGram) -> f64 {
+    profile_similarity(a, b, qgram, |a, b| {
+        let squares = |profile: &HashMap<&[u8], usize>| {
+            profile.values().map(|&n| (n * n) as f64).sum::<f64>()
+        };
+        let dot = a
+            .iter()
+            .filter_map(|(gram, &n)| b.get(gram).map(|&m| (n * m) as f64))
+            .sum::<f64>();
+
+        ratio(dot, (squares(a) * squares(b)).sqrt()).map(|similarity| similarity.min(1.0))
+    })
+}
@@ -0,0 +1,178 @@
+//! Gestalt pattern matching, as described by J. W. Ratcliff and D. E. Metzener in
+//! "Pattern Matching: the Gestalt Approach" (1988), in the flavour of Python's
+//! `difflib.SequenceMatcher`.
+//!
+//! The longest common substring of the strings, measured in chars as difflib does rather than
+//! in bytes as `StrExt::longest_common_substring` does, is matched first, then the same is done
+//! recursively on the regions on its left and on its right, so that the matching blocks
+//! are the ones a human reader would pick.
+
+use std::ops::Range;
+
+use crate::suffix_automaton::SuffixAutomaton;
+
+/// A substring common to a source string and a target string,
+/// as computed by `StrExt::matching_blocks`.
+///
+/// `source` and `target` are the byte ranges of the substring in the respective strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatchingBlock {
+    pub source: Range<usize>,
+    pub target: Range<usize>,
+}
+
+/// An operation of a diff turning a source string into a target string,
+/// as computed by `StrExt::opcodes`.
+///
+/// Each operation spans a run of chars in each string, `source` and `target` are the
+/// byte ranges of those runs in the respective strings. Operations that do not consume
+/// any char of one of the strings hold an empty range at the position where they apply.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{Opcode, StrExt};
+///
+/// assert_eq!(
+///     "qabxcd".opcodes("abycdf"),
+///     [
+///         Opcode::Delete { source: 0..1, target: 0..0 },
+///         Opcode::Equal { source: 1..3, target: 0..2 },
+///         Opcode::Replace { source: 3..4, target: 2..3 },
+///         Opcode::Equal { source: 4..6, target: 3..5 },
+///         Opcode::Insert { source: 6..6, target: 5..6 },
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    /// The run of the source is equal to the run of the target.
+    Equal {
+        source: Range<usize>,
+        target: Range<usize>,
+    },
+    /// The run of the source is replaced with the run of the target.
+    Replace {
+        source: Range<usize>,
+        target: Range<usize>,
+    },
+    /// The run of the source is deleted.
+    Delete {
+        source: Range<usize>,
+        target: Range<usize>,
+    },
+    /// The run of the target is inserted in the source.
+    Insert {
+        source: Range<usize>,
+        target: Range<usize>,
+    },
+}
+
+/// Computes the matching blocks between `source` and `target`, sorted by position,
+/// with adjacent blocks merged and followed by an empty block at the end of both strings.
+pub(crate) fn matching_blocks(source: &str, target: &str) -> Vec<MatchingBlock> {
+    let mut blocks = Vec::new();
+    // the regions left to match, as byte ranges in both strings.
+    let mut regions = vec![(0..source.len(), 0..target.len())];
+
+    while let Some((source_region, target_region)) = regions.pop() {
+        let (source_text, target_text) = (
+            &source[source_region.clone()],
+            &target[target_region.clone()],
+        );
+
+        if source_text.is_empty() || target_text.is_empty() {
+            continue;
+        }
+
+        // the first occurrence in the source and then in the target, as difflib does.
+        let matched = SuffixAutomaton::new(source_text).longest_match(target_text);
+        if matched.is_empty() {
+            continue;
+        }
+
+        let source_start =
+            source_region.start + (matched.as_ptr() as usize - source_text.as_ptr() as usize);
+        let target_start = target_region.start + target_text.find(matched).unwrap();
+        let block = MatchingBlock {
+            source: source_start..source_start + matched.len(),
+            target: target_start..target_start + matched.len(),
+        };
+
+        regions.push((
+            source_region.start..block.source.start,
+            target_region.start..block.target.start,
+        ));
+        regions.push((
+            block.source.end..source_region.end,
+            block.target.end..target_region.end,
+        ));
+        blocks.push(block);
+    }
+
+    blocks.sort_by_key(|block| block.source.start);
+
+    let mut merged: Vec<MatchingBlock> = Vec::with_capacity(blocks.len() + 1);
+    for block in blocks {
+        match merged.last_mut() {
+            Some(last)
+                if last.source.end == block.source.start
+                    && last.target.end == block.target.start =>
+            {
+                last.source.end = block.source.end;
+                last.target.end = block.target.end;
+            }
+            _ => merged.push(block),
+        }
+    }
+
+    merged.push(MatchingBlock {
+        source: source.len()..source.len(),
+        target: target.len()..target.len(),
+    });
+    merged
+}
+
+/// Computes the operations turning `source` into `target`, given their matching blocks.
+pub(crate) fn opcodes(blocks: &[MatchingBlock]) -> Vec<Opcode> {
+    let mut opcodes = Vec::with_capacity(2 * blocks.len());
+    let (mut i, mut j) = (0, 0);
+
+    for block in blocks {
+        let (source, target) = (i..block.source.start, j..block.target.start);
+
+        match (source.is_empty(), target.is_empty()) {
+            (false, false) => opcodes.push(Opcode::Replace { source, target }),
+            (false, true) => opcodes.push(Opcode::Delete { source, target }),
+            (true, false) => opcodes.push(Opcode::Insert { source, target }),
+            (true, true) => {}
+        }
+
+        if !block.source.is_empty() {
+            opcodes.push(Opcode::Equal {
+                source: block.source.clone(),
+                target: block.target.clone(),
+            });
+        }
+
+        i = block.source.end;
+        j = block.target.end;
+    }
+
+    opcodes
+}
+
+/// Computes the similarity between `source` and `target` given their matching blocks,
+/// that is twice the number of matching chars divided by the total number of chars.
+pub(crate) fn similarity(source: &str, target: &str, blocks: &[MatchingBlock]) -> f64 {
+    let len = source.chars().count() + target.chars().count();
+
+    if len == 0 {
+        return 1.0;
+    }
+
+    let matches = blocks
+        .iter()
+        .map(|block| source[block.source.clone()].chars().count())
+        .sum::<usize>();
+    2.0 * matches as f64 / len as f64
+}
@@ -0,0 +1,239 @@
+//! Suffix automaton, the minimal automaton recognizing every substring of a string,
+//! built in linear time by the online algorithm of A. Blumer et al. in "The smallest
+//! automaton recognizing the subwords of a text" (1985).
+//!
+//! Each state stands for a set of substrings sharing the same end positions in the string,
+//! which are suffixes of each other with lengths in `(len(link), len]`.
+
+use std::cmp::Reverse;
+
+use crate::ascii;
+
+struct State {
+    /// The length in chars of the longest substring of the state.
+    len: usize,
+    /// The state of the longest suffix not belonging to this state, `None` for the initial state.
+    link: Option<usize>,
+    /// The index of the last char of the first occurrence of the substrings of the state.
+    first_end: usize,
+    /// The transitions, sorted by char.
+    next: Vec<(char, usize)>,
+}
+
+impl State {
+    fn transition(&self, c: char) -> Option<usize> {
+        self.next
+            .binary_search_by_key(&c, |&(c, _)| c)
+            .ok()
+            .map(|i| self.next[i].1)
+    }
+
+    fn set_transition(&mut self, c: char, state: usize) {
+        match self.next.binary_search_by_key(&c, |&(c, _)| c) {
+            Ok(i) => self.next[i].1 = state,
+            Err(i) => self.next.insert(i, (c, state)),
+        }
+    }
+}
+
+pub(crate) struct SuffixAutomaton<'a> {
+    text: &'a str,
+    /// The length of the text in chars.
+    len: usize,
+    /// The byte index of each char of the text, plus the length of the text.
+    /// `None` when the text is made up of ASCII chars only, whose byte indices are their char indices.
+    offsets: Option<Vec<usize>>,
+    states: Vec<State>,
+}
+
+impl<'a> SuffixAutomaton<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        let is_ascii = ascii::is_ascii(text);
+        let offsets = (!is_ascii).then(|| {
+            let mut offsets = Vec::with_capacity(text.len() + 1);
+            offsets.extend(text.char_indices().map(|(i, _)| i));
+            offsets.push(text.len());
+            offsets
+        });
+        let len = offsets
+            .as_ref()
+            .map_or(text.len(), |offsets| offsets.len() - 1);
+
+        let mut automaton = Self {
+            text,
+            len,
+            offsets,
+            states: Vec::with_capacity(2 * (len + 1)),
+        };
+
+        automaton.states.push(State {
+            len: 0,
+            link: None,
+            first_end: 0,
+            next: Vec::new(),
+        });
+
+        let mut last = 0;
+        for (position, c) in ascii::chars(text, is_ascii).enumerate() {
+            last = automaton.extend(last, position, c);
+        }
+
+        automaton
+    }
+
+    fn extend(&mut self, last: usize, position: usize, c: char) -> usize {
+        let current = self.states.len();
+        self.states.push(State {
+            len: self.states[last].len + 1,
+            link: Some(0),
+            first_end: position,
+            next: Vec::new(),
+        });
+
+        let mut p = Some(last);
+        while let Some(state) = p {
+            if self.states[state].transition(c).is_some() {
+                break;
+            }
+
+            self.states[state].set_transition(c, current);
+            p = self.states[state].link;
+        }
+
+        if let Some(p) = p {
+            let q = self.states[p].transition(c).unwrap();
+
+            if self.states[p].len + 1 == self.states[q].len {
+                self.states[current].link = Some(q);
+            } else {
+                let clone = self.states.len();
+                self.states.push(State {
+                    len: self.states[p].len + 1,
+                    link: self.states[q].link,
+                    first_end: self.states[q].first_end,
+                    next: self.states[q].next.clone(),
+                });
+
+                let mut p = Some(p);
+                while let Some(state) = p {
+                    if self.states[state].transition(c) != Some(q) {
+                        break;
+                    }
+
+                    self.states[state].set_transition(c, clone);
+                    p = self.states[state].link;
+                }
+
+                self.states[q].link = Some(clone);
+                self.states[current].link = Some(clone);
+            }
+        }
+
+        current
+    }
+
+    /// Returns the byte index of the char at `position` in the text.
+    fn offset(&self, position: usize) -> usize {
+        self.offsets
+            .as_ref()
+            .map_or(position, |offsets| offsets[position])
+    }
+
+    /// Returns the states sorted by decreasing length of their longest substring.
+    fn states_by_decreasing_len(&self) -> Vec<usize> {
+        // counting sort, the length of any substring is bounded by the length of the text.
+        let mut starts = vec![0; self.len + 1];
+        for state in &self.states {
+            starts[state.len] += 1;
+        }
+
+        let mut start = 0;
+        for slot in starts.iter_mut().rev() {
+            let count = *slot;
+            *slot = start;
+            start += count;
+        }
+
+        let mut sorted = vec![0; self.states.len()];
+        for (i, state) in self.states.iter().enumerate() {
+            sorted[starts[state.len]] = i;
+            starts[state.len] += 1;
+        }
+
+        sorted
+    }
+
+    /// Returns, for every state, the length in chars of the longest
+    /// substring of the state that also occurs in `other`.
+    pub(crate) fn match_lengths(&self, other: &str) -> Vec<usize> {
+        let mut lengths = vec![0; self.states.len()];
+        let (mut state, mut len) = (0, 0);
+
+        for c in ascii::chars(other, ascii::is_ascii(other)) {
+            loop {
+                if let Some(next) = self.states[state].transition(c) {
+                    state = next;
+                    len += 1;
+                    break;
+                }
+
+                match self.states[state].link {
+                    Some(link) => {
+                        state = link;
+                        len = self.states[state].len;
+                    }
+                    None => {
+                        len = 0;
+                        break;
+                    }
+                }
+            }
+
+            lengths[state] = lengths[state].max(len);
+        }
+
+        // every suffix of a matching substring matches too.
+        for state in self.states_by_decreasing_len() {
+            match self.states[state].link {
+                Some(link) if lengths[state] > 0 => lengths[link] = self.states[link].len,
+                _ => {}
+            }
+        }
+
+        lengths
+    }
+
+    /// Returns the first occurrence in the text of the longest substring, in chars,
+    /// that also occurs in `other`. The substring is empty if the strings share no char.
+    pub(crate) fn longest_match(&self, other: &str) -> &'a str {
+        let lengths = self.match_lengths(other);
+
+        self.states
+            .iter()
+            .zip(&lengths)
+            .filter(|(_, &len)| len > 0)
+            .min_by_key(|(state, &len)| (Reverse(len), state.first_end))
+            .map_or(&self.text[..0], |(state, &len)| {
+                let start = self.offset(state.first_end + 1 - len);
+                let end = self.offset(state.first_end + 1);
+                &self.text[start..end]
+            })
+    }
+
+    /// Returns the first occurrence in the text of the substrings of each state that are
+    /// `lengths[state]` chars long, skipping empty substrings.
+    pub(crate) fn substrings<'b>(
+        &'b self,
+        lengths: &'b [usize],
+    ) -> impl Iterator<Item = &'a str> + 'b {
+        self.states
+            .iter()
+            .zip(lengths)
+            .filter(|(_, &len)| len > 0)
+            .map(move |(state, &len)| {
+                let start = self.offset(state.first_end + 1 - len);
+                let end = self.offset(state.first_end + 1);
+                &self.text[start..end]
+            })
+    }
+}
@@ -0,0 +1,496 @@
+//! Symmetric delete spelling correction, as described by W. Garbe in "SymSpell" (2012).
+//!
+//! Every string obtained by deleting up to `max` chars from a word of the dictionary is
+//! indexed beforehand, so that the words within `max` edits of a query are found among the
+//! words sharing one of those strings with the query, without generating any insertion,
+//! substitution or transposition. Only the deletions of the first `prefix_len` chars of the
+//! words are indexed, which bounds the size of the index while still finding every word.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::StrExt;
+
+/// A word of the dictionary, along with its length in chars and its frequency.
+#[derive(Debug, Clone)]
+struct Entry {
+    word: String,
+    len: usize,
+    frequency: u64,
+}
+
+/// A corrected word of a phrase, along with its distance from the original one and
+/// the probability of its occurrence.
+struct Part {
+    text: String,
+    distance: usize,
+    probability: f64,
+}
+
+/// A spelling correction engine over a dictionary of words and their frequencies, which
+/// finds the words within a bounded Levenshtein distance from a query by the symmetric
+/// delete algorithm, computing the distance from a small fraction of the dictionary only.
+///
+/// Suggestions are sorted by distance, then by decreasing frequency, then by insertion order.
+///
+/// # Examples
+/// ```rust
+/// use string_more::SymSpell;
+///
+/// let mut dictionary = SymSpell::new(2);
+/// dictionary.extend([("where", 60), ("is", 90), ("the", 100), ("love", 20), ("live", 30)]);
+///
+/// assert_eq!(dictionary.lookup("lave", 1), [("live", 1), ("love", 1)]);
+/// assert_eq!(dictionary.lookup("teh", 2), [("the", 2)]);
+/// assert_eq!(
+///     dictionary.lookup_compound("whereis th elove", 2),
+///     ("where is the love".to_string(), 3)
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SymSpell {
+    max: usize,
+    prefix_len: usize,
+    entries: Vec<Entry>,
+    /// The index of each word in `entries`.
+    words: HashMap<String, usize>,
+    /// The words whose prefix turns into each string by deleting up to `max` chars.
+    deletes: HashMap<String, Vec<usize>>,
+    /// The sum of the frequencies of the words.
+    total: u64,
+}
+
+impl SymSpell {
+    /// The length in chars of the prefixes indexed by default.
+    pub const DEFAULT_PREFIX_LEN: usize = 7;
+
+    /// Creates an empty dictionary finding the words within `max` edits of a query.
+    pub fn new(max: usize) -> Self {
+        Self::with_prefix_len(max, Self::DEFAULT_PREFIX_LEN)
+    }
+
+    /// Creates an empty dictionary finding the words within `max` edits of a query, indexing
+    /// the deletions of the first `prefix_len` chars of each word. Shorter prefixes make a
+    /// smaller index, at the cost of comparing a query with more words.
+    ///
+    /// # Panics
+    /// Panics if `prefix_len` is not greater than `max`.
+    pub fn with_prefix_len(max: usize, prefix_len: usize) -> Self {
+        assert!(
+            prefix_len > max,
+            "the prefix length must be greater than the maximal distance"
+        );
+
+        Self {
+            max,
+            prefix_len,
+            entries: Vec::new(),
+            words: HashMap::new(),
+            deletes: HashMap::new(),
+            total: 0,
+        }
+    }
+
+    /// Returns the maximal distance of the words found from a query.
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Returns the number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the dictionary contains no words.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the frequency of `word`, or `None` if the dictionary does not contain it.
+    pub fn frequency(&self, word: &str) -> Option<u64> {
+        self.words
+            .get(word)
+            .map(|&index| self.entries[index].frequency)
+    }
+
+    /// Inserts `word` in the dictionary with `frequency` occurrences.
+    /// Returns `false` if the dictionary already contains `word`, adding `frequency`
+    /// to its occurrences.
+    pub fn insert(&mut self, word: &str, frequency: u64) -> bool {
+        self.total = self.total.saturating_add(frequency);
+
+        if let Some(&index) = self.words.get(word) {
+            let entry = &mut self.entries[index];
+            entry.frequency = entry.frequency.saturating_add(frequency);
+            return false;
+        }
+
+        let index = self.entries.len();
+        let prefix = word.chars().take(self.prefix_len).collect::<Vec<_>>();
+        for delete in deletes(&prefix, self.max) {
+            self.deletes.entry(delete).or_default().push(index);
+        }
+
+        self.entries.push(Entry {
+            word: word.to_string(),
+            len: word.chars().count(),
+            frequency,
+        });
+        self.words.insert(word.to_string(), index);
+        true
+    }
+
+    /// Returns the words whose Levenshtein distance from `query` does not exceed `max`,
+    /// along with their distance. `max` is bounded by the maximal distance of the dictionary.
+    pub fn lookup(&self, query: &str, max: usize) -> Vec<(&str, usize)> {
+        let max = max.min(self.max);
+        let len = query.chars().count();
+        let prefix = query.chars().take(self.prefix_len).collect::<Vec<_>>();
+        let mut candidates = HashSet::new();
+        let mut matches = Vec::new();
+
+        for delete in deletes(&prefix, max) {
+            for &index in self.deletes.get(&delete).into_iter().flatten() {
+                let entry = &self.entries[index];
+                if entry.len.max(len) - entry.len.min(len) > max || !candidates.insert(index) {
+                    continue;
+                }
+
+                if let Some(distance) = query.levenshtein_distance_within(&entry.word, max) {
+                    matches.push((index, distance));
+                }
+            }
+        }
+
+        matches.sort_unstable_by(|&(a, da), &(b, db)| {
+            let (a_entry, b_entry) = (&self.entries[a], &self.entries[b]);
+            da.cmp(&db)
+                .then(b_entry.frequency.cmp(&a_entry.frequency))
+                .then(a.cmp(&b))
+        });
+
+        matches
+            .into_iter()
+            .map(|(index, distance)| (self.entries[index].word.as_str(), distance))
+            .collect()
+    }
+
+    /// Corrects the words of `phrase`, split by whitespace, also joining the words wrongly
+    /// split and splitting the words wrongly joined, and returns the corrected phrase along
+    /// with its Levenshtein distance from `phrase`.
+    ///
+    /// Each word is replaced by its best suggestion within `max` edits, unless joining it
+    /// with the previous word or splitting it in two words of the dictionary takes fewer edits,
+    /// or as many edits and gives more probable words, given their frequencies. The words
+    /// with no suggestions are left unchanged.
+    pub fn lookup_compound(&self, phrase: &str, max: usize) -> (String, usize) {
+        let terms = phrase.split_whitespace().collect::<Vec<_>>();
+        let mut parts: Vec<Part> = Vec::with_capacity(terms.len());
+        let mut joined = false;
+
+        for (i, term) in terms.iter().enumerate() {
+            let best = self.best(term, max);
+
+            // joins the term with the previous one, unless the previous one was joined already.
+            if i > 0 && !joined {
+                let combined = format!("{}{}", terms[i - 1], term);
+
+                if let (Some(previous), Some(mut combination)) =
+                    (parts.last(), self.best(&combined, max))
+                {
+                    // the space between the terms is deleted too.
+                    combination.distance += 1;
+
+                    let current = best
+                        .as_ref()
+                        .map_or((max + 1, 0.0), |best| (best.distance, best.probability));
+                    let distance = previous.distance + current.0;
+                    let probability = previous.probability * current.1;
+
+                    if combination.distance < distance
+                        || (combination.distance == distance
+                            && combination.probability > probability)
+                    {
+                        *parts.last_mut().unwrap() = combination;
+                        joined = true;
+                        continue;
+                    }
+                }
+            }
+
+            joined = false;
+
+            let part = match best {
+                Some(best) if best.distance == 0 => best,
+                best => {
+                    let split = self.best_split(term, max);
+                    match (best, split) {
+                        (Some(best), Some(split))
+                            if split.distance < best.distance
+                                || (split.distance == best.distance
+                                    && split.probability > best.probability) =>
+                        {
+                            split
+                        }
+                        (Some(best), _) => best,
+                        (None, Some(split)) => split,
+                        (None, None) => Part {
+                            text: term.to_string(),
+                            distance: max + 1,
+                            probability: 0.0,
+                        },
+                    }
+                }
+            };
+
+            parts.push(part);
+        }
+
+        let corrected = parts
+            .iter()
+            .map(|part| part.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let distance = phrase.levenshtein_distance(&corrected);
+        (corrected, distance)
+    }
+
+    /// Returns the best suggestion for `term` within `max` edits, if any.
+    fn best(&self, term: &str, max: usize) -> Option<Part> {
+        self.lookup(term, max)
+            .first()
+            .map(|&(word, distance)| Part {
+                text: word.to_string(),
+                distance,
+                probability: self.probability(word),
+            })
+    }
+
+    /// Returns the best split of `term` in two words within `max` edits, if any.
+    fn best_split(&self, term: &str, max: usize) -> Option<Part> {
+        let mut best: Option<Part> = None;
+
+        for (index, _) in term.char_indices().skip(1) {
+            let (left, right) = match (
+                self.best(&term[..index], max),
+                self.best(&term[index..], max),
+            ) {
+                (Some(left), Some(right)) => (left, right),
+                _ => continue,
+            };
+
+            let text = format!("{} {}", left.text, right.text);
+            let distance = match term.levenshtein_distance_within(&text, max) {
+                Some(distance) => distance,
+                None => continue,
+            };
+            let probability = left.probability * right.probability;
+
+            let is_better = best.as_ref().map_or(true, |best| {
+                distance < best.distance
+                    || (distance == best.distance && probability > best.probability)
+            });
+            if is_better {
+                best = Some(Part {
+                    text,
+                    distance,
+                    probability,
+                });
+            }
+        }
+
+        best
+    }
+
+    /// Returns the probability of the occurrence of `word`, given the frequencies of the words.
+    fn probability(&self, word: &str) -> f64 {
+        self.frequency(word)
+            .map_or(0.0, |frequency| frequency as f64 / self.total.max(1) as f64)
+    }
+}
+
+impl Default for SymSpell {
+    /// Creates an empty dictionary finding the words within 2 edits of a query.
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl<S: AsRef<str>> Extend<(S, u64)> for SymSpell {
+    fn extend<I: IntoIterator<Item = (S, u64)>>(&mut self, iter: I) {
+        for (word, frequency) in iter {
+            self.insert(word.as_ref(), frequency);
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<(S, u64)> for SymSpell {
+    fn from_iter<I: IntoIterator<Item = (S, u64)>>(iter: I) -> Self {
+        let mut dictionary = Self::default();
+        dictionary.extend(iter);
+        dictionary
+    }
+}
+
+/// Returns the distinct strings obtained by deleting up to `max` chars from `chars`,
+/// including `chars` itself.
+fn deletes(chars: &[char], max: usize) -> HashSet<String> {
+    let mut deletes = HashSet::new();
+    let mut current = vec![chars.to_vec()];
+    deletes.insert(chars.iter().collect());
+
+    for _ in 0..max.min(chars.len()) {
+        let mut next = Vec::new();
+
+        for chars in &current {
+            for i in 0..chars.len() {
+                let mut delete = chars.clone();
+                delete.remove(i);
+
+                if deletes.insert(delete.iter().collect()) {
+                    next.push(delete);
+                }
+            }
+        }
+
+        current = next;
+    }
+
+    deletes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use super::SymSpell;
+    use crate::StrExt;
+
+    const WORDS: [(&str, u64); 16] = [
+        ("book", 50),
+        ("books", 20),
+        ("cake", 10),
+        ("boo", 5),
+        ("boon", 5),
+        ("cook", 30),
+        ("cape", 10),
+        ("cart", 15),
+        ("", 1),
+        ("a", 90),
+        ("bo", 2),
+        ("b·ok", 1),
+        ("kitten", 8),
+        ("sitting", 8),
+        ("bookkeeper", 3),
+        ("bookkeeping", 4),
+    ];
+
+    const QUERIES: [&str; 11] = [
+        "",
+        "a",
+        "bo",
+        "bool",
+        "caqe",
+        "kitchen",
+        "zzzz",
+        "books",
+        "b·k",
+        "bokkeeper",
+        "bookeepinq",
+    ];
+
+    #[test]
+    fn insert() {
+        let mut dictionary = SymSpell::default();
+        assert!(dictionary.is_empty());
+        assert_eq!(dictionary.max(), 2);
+
+        for (i, (word, frequency)) in WORDS.iter().enumerate() {
+            assert!(dictionary.insert(word, *frequency));
+            assert_eq!(dictionary.len(), i + 1);
+        }
+
+        assert!(!dictionary.insert("book", 5));
+        assert_eq!(dictionary.len(), WORDS.len());
+        assert_eq!(dictionary.frequency("book"), Some(55));
+        assert_eq!(dictionary.frequency("bool"), None);
+    }
+
+    #[test]
+    fn lookup() {
+        for (max, prefix_len) in [(0, 1), (1, 2), (1, 7), (2, 3), (2, 7), (3, 4)] {
+            let mut dictionary = SymSpell::with_prefix_len(max, prefix_len);
+            dictionary.extend(WORDS);
+
+            for query in QUERIES {
+                for k in 0..=max + 1 {
+                    let mut expected = WORDS
+                        .iter()
+                        .map(|(word, frequency)| {
+                            (*word, query.levenshtein_distance(word), frequency)
+                        })
+                        .filter(|&(_, distance, _)| distance <= k.min(max))
+                        .collect::<Vec<_>>();
+                    expected
+                        .sort_by_key(|&(_, distance, frequency)| (distance, Reverse(*frequency)));
+                    let expected = expected
+                        .into_iter()
+                        .map(|(word, distance, _)| (word, distance))
+                        .collect::<Vec<_>>();
+
+                    assert_eq!(
+                        dictionary.lookup(query, k),
+                        expected,
+                        "{query} {k} {max} {prefix_len}"
+                    );
+                }
+            }
+        }
+
+        assert!(SymSpell::default().lookup("book", 2).is_empty());
+    }
+
+    #[test]
+    fn lookup_compound() {
+        let dictionary: SymSpell = [
+            ("where", 60),
+            ("is", 90),
+            ("the", 100),
+            ("love", 20),
+            ("live", 30),
+            ("in", 80),
+            ("into", 40),
+            ("to", 70),
+            ("book", 50),
+            ("keeper", 5),
+            ("bookkeeper", 3),
+            ("go", 35),
+        ]
+        .into_iter()
+        .collect();
+
+        let cases = [
+            ("", ""),
+            ("the love", "the love"),
+            ("whereis th elove", "where is the love"),
+            ("wheer is teh loev", "where is the love"),
+            ("in to", "in to"),
+            ("go intoo the", "go into the"),
+            ("whe re is", "where is"),
+            ("book keeper", "book keeper"),
+            ("bok keper", "book keeper"),
+            ("thelove", "the love"),
+            ("the xyzzy love", "the xyzzy love"),
+        ];
+
+        for (phrase, corrected) in cases {
+            assert_eq!(
+                dictionary.lookup_compound(phrase, 2),
+                (
+                    corrected.to_string(),
+                    phrase.levenshtein_distance(corrected)
+                ),
+                "{phrase}"
+            );
+        }
+    }
+}
@@ -0,0 +1,454 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{grapheme, myers};
+
+/// The unit in which strings are split to be compared.
+///
+/// # Examples
+/// ```rust
+/// use string_more::{StrExt, Unit};
+///
+/// // "é" as "e" followed by a combining acute accent.
+/// let (a, b) = ("cafe\u{301}", "cafes");
+/// assert_eq!(a.hamming_distance_by(b, Unit::Byte), None);
+/// assert_eq!(a.hamming_distance_by(b, Unit::Char), Some(1));
+/// assert_eq!(a.hamming_distance_by(b, Unit::Grapheme), None);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// The bytes of the UTF-8 encoding.
+    Byte,
+    /// The Unicode scalar values, that is `char`s.
+    #[default]
+    Char,
+    /// The extended grapheme clusters, as defined by Unicode Standard Annex #29
+    /// with the boundary rules of Unicode 17.0, which match what users perceive as
+    /// single characters, Indic conjuncts included.
+    Grapheme,
+}
+
+impl Unit {
+    /// Returns the number of units of `s`.
+    pub(crate) fn count(self, s: &str) -> usize {
+        match self {
+            Self::Byte => s.len(),
+            Self::Char => s.chars().count(),
+            Self::Grapheme => grapheme::graphemes(s).count(),
+        }
+    }
+
+    /// Returns the byte index of the start of each unit of `s`, followed by the length of `s`.
+    pub(crate) fn boundaries(self, s: &str) -> Vec<usize> {
+        let mut boundaries = match self {
+            Self::Byte => (0..s.len()).collect(),
+            Self::Char => s.char_indices().map(|(i, _)| i).collect(),
+            Self::Grapheme => grapheme::graphemes(s)
+                .map(|g| g.as_ptr() as usize - s.as_ptr() as usize)
+                .collect::<Vec<_>>(),
+        };
+
+        boundaries.push(s.len());
+        boundaries
+    }
+}
+
+/// The units in which strings are split to be compared by the metrics:
+/// bytes, chars or extended grapheme clusters.
+pub(crate) trait Symbol: Copy + Eq + Hash {
+    /// Returns the ASCII byte the unit stands for, if it is a single ASCII char.
+    fn as_ascii(self) -> Option<u8>;
+}
+
+impl Symbol for u8 {
+    fn as_ascii(self) -> Option<u8> {
+        self.is_ascii().then_some(self)
+    }
+}
+
+impl Symbol for char {
+    fn as_ascii(self) -> Option<u8> {
+        self.is_ascii().then_some(self as u8)
+    }
+}
+
+impl Symbol for &str {
+    fn as_ascii(self) -> Option<u8> {
+        match self.as_bytes() {
+            &[b] if b.is_ascii() => Some(b),
+            _ => None,
+        }
+    }
+}
+
+/// Splits `s` in extended grapheme clusters.
+pub(crate) fn graphemes(s: &str) -> Vec<&str> {
+    grapheme::graphemes(s).collect()
+}
+
+/// Strips the common prefix and suffix of `source` and `target`.
+fn strip_common_affixes<'a, T: PartialEq>(source: &'a [T], target: &'a [T]) -> (&'a [T], &'a [T]) {
+    let end = source
+        .iter()
+        .rev()
+        .zip(target.iter().rev())
+        .take_while(|(l, r)| l == r)
+        .count();
+    let (source, target) = (&source[..source.len() - end], &target[..target.len() - end]);
+
+    let start = source
+        .iter()
+        .zip(target)
+        .take_while(|(l, r)| l == r)
+        .count();
+    (&source[start..], &target[start..])
+}
+
+/// Computes the Levenshtein distance between the units of `source` and `target`.
+pub(crate) fn levenshtein_distance<T: Symbol>(source: &[T], target: &[T]) -> usize {
+    let (source, target) = strip_common_affixes(source, target);
+
+    if source.is_empty() || target.is_empty() {
+        return source.len() + target.len();
+    }
+
+    // the bit-vectors span the units of the pattern, so we assign the
+    // shortest sequence to pattern in order to use as few words as possible.
+    let (text, pattern) = if source.len() < target.len() {
+        (target, source)
+    } else {
+        (source, target)
+    };
+
+    let (text_units, pattern_units) = (text.iter().copied(), pattern.iter().copied());
+    if pattern.len() <= u64::BITS as usize {
+        myers::distance(text_units, pattern_units, pattern.len())
+    } else {
+        myers::blocked_distance(text_units, pattern_units, pattern.len())
+    }
+}
+
+/// Computes the optimal string alignment distance between the units of `source` and `target`.
+pub(crate) fn osa_distance<T: Symbol>(source: &[T], target: &[T]) -> usize {
+    let (source, target) = strip_common_affixes(source, target);
+
+    if source.is_empty() || target.is_empty() {
+        return source.len() + target.len();
+    }
+
+    // micro optimization: rows have the same cardinality of target's units,
+    // so we bet on the shortest sequence to reduce allocations.
+    let (source, target) = if source.len() < target.len() {
+        (target, source)
+    } else {
+        (source, target)
+    };
+
+    osa_distance_streamed(source.iter().copied(), target.iter().copied(), target.len())
+}
+
+/// Computes the optimal string alignment distance between the units read from `source` and
+/// `target`, the latter being made up of `target_len` units and read once per unit of the former.
+pub(crate) fn osa_distance_streamed<T, S, I>(source: S, target: I, target_len: usize) -> usize
+where
+    T: Symbol,
+    S: Iterator<Item = T>,
+    I: Iterator<Item = T> + Clone,
+{
+    // rows `i - 2`, `i - 1` and `i` of the distance matrix respectively.
+    let mut transposed = vec![0; target_len + 1];
+    let mut previous = (0..=target_len).collect::<Vec<_>>();
+    let mut costs = vec![0; target_len + 1];
+    let mut previous_source_unit = None;
+
+    for (source_index, source_unit) in source.enumerate() {
+        costs[0] = source_index + 1;
+        let mut previous_target_unit = None;
+
+        for (target_index, target_unit) in target.clone().enumerate() {
+            let substitution = previous[target_index] + (source_unit != target_unit) as usize;
+            let deletion = previous[target_index + 1] + 1;
+            let insertion = costs[target_index] + 1;
+            let mut cost = usize::min(usize::min(deletion, insertion), substitution);
+
+            if previous_source_unit == Some(target_unit)
+                && previous_target_unit == Some(source_unit)
+            {
+                cost = usize::min(cost, transposed[target_index - 1] + 1);
+            }
+
+            costs[target_index + 1] = cost;
+            previous_target_unit = Some(target_unit);
+        }
+
+        std::mem::swap(&mut transposed, &mut previous);
+        std::mem::swap(&mut previous, &mut costs);
+        previous_source_unit = Some(source_unit);
+    }
+
+    previous[target_len]
+}
+
+/// Computes the unrestricted Damerau-Levenshtein distance between the units of `source` and
+/// `target`, by means of the Lowrance-Wagner algorithm, see:
+/// https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance#Distance_with_adjacent_transpositions
+pub(crate) fn damerau_levenshtein_distance<T: Symbol>(source: &[T], target: &[T]) -> usize {
+    let (source, target) = strip_common_affixes(source, target);
+
+    if source.is_empty() || target.is_empty() {
+        return source.len() + target.len();
+    }
+
+    damerau_levenshtein_distance_streamed(
+        source.iter().copied(),
+        source.len(),
+        target.iter().copied(),
+        target.len(),
+    )
+}
+
+/// Computes the unrestricted Damerau-Levenshtein distance between the units read from `source`
+/// and `target`, made up of `source_len` and `target_len` units respectively, the latter being
+/// read once per unit of the former.
+pub(crate) fn damerau_levenshtein_distance_streamed<T, S, I>(
+    source: S,
+    source_len: usize,
+    target: I,
+    target_len: usize,
+) -> usize
+where
+    T: Symbol,
+    S: Iterator<Item = T>,
+    I: Iterator<Item = T> + Clone,
+{
+    let (rows, columns) = (source_len + 2, target_len + 2);
+    let infinity = source_len + target_len;
+
+    // the distance matrix is stored flattened, row by row, with an extra
+    // leading row and column holding the `infinity` sentinel.
+    let mut costs = vec![0; rows * columns];
+    // the last row in which each unit of target has been seen in source.
+    let mut last_rows = HashMap::<T, usize>::new();
+
+    costs[0] = infinity;
+    for i in 0..=source_len {
+        costs[(i + 1) * columns] = infinity;
+        costs[(i + 1) * columns + 1] = i;
+    }
+    for j in 0..=target_len {
+        costs[j + 1] = infinity;
+        costs[columns + j + 1] = j;
+    }
+
+    for (i, source_unit) in (1..).zip(source) {
+        // the last column in which a unit of source has matched in the current row.
+        let mut last_column = 0;
+
+        for (j, target_unit) in (1..).zip(target.clone()) {
+            let k = last_rows.get(&target_unit).copied().unwrap_or(0);
+            let l = last_column;
+            let cost = if source_unit == target_unit {
+                last_column = j;
+                0
+            } else {
+                1
+            };
+
+            let substitution = costs[i * columns + j] + cost;
+            let insertion = costs[(i + 1) * columns + j] + 1;
+            let deletion = costs[i * columns + j + 1] + 1;
+            let transposition = costs[k * columns + l] + (i - k - 1) + 1 + (j - l - 1);
+
+            costs[(i + 1) * columns + j + 1] = usize::min(
+                usize::min(substitution, insertion),
+                usize::min(deletion, transposition),
+            );
+        }
+
+        last_rows.insert(source_unit, i);
+    }
+
+    costs[rows * columns - 1]
+}
+
+/// Computes the Jaro similarity between the units of `source` and `target`.
+pub(crate) fn jaro_similarity<T: Symbol>(source: &[T], target: &[T]) -> f64 {
+    jaro_similarity_streamed(
+        source.iter().copied(),
+        source.len(),
+        target.iter().copied(),
+        target.len(),
+    )
+}
+
+/// Computes the Jaro similarity between the units read from `source` and `target`,
+/// made up of `source_len` and `target_len` units respectively.
+pub(crate) fn jaro_similarity_streamed<T, S, I>(
+    source: S,
+    source_len: usize,
+    target: I,
+    target_len: usize,
+) -> f64
+where
+    T: Symbol,
+    S: Iterator<Item = T> + Clone,
+    I: Iterator<Item = T> + Clone,
+{
+    if source_len == 0 && target_len == 0 {
+        return 1.0;
+    }
+
+    if source_len == 0 || target_len == 0 {
+        return 0.0;
+    }
+
+    // units are considered matching only if they are not farther than `window`.
+    let window = (usize::max(source_len, target_len) / 2).saturating_sub(1);
+    let mut source_matches = vec![false; source_len];
+    let mut target_matches = vec![false; target_len];
+    let mut matches = 0;
+    // the units of target from the start of the window on, which only moves forward.
+    let (mut window_units, mut window_start) = (target.clone().enumerate(), 0);
+
+    for (i, source_unit) in source.clone().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = usize::min(i + window + 1, target_len);
+
+        while window_start < start {
+            window_units.next();
+            window_start += 1;
+        }
+
+        for (j, target_unit) in window_units.clone().take(end.saturating_sub(start)) {
+            if !target_matches[j] && target_unit == source_unit {
+                source_matches[i] = true;
+                target_matches[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // half the number of matching units that appear in a different order, rounded down
+    // as in Winkler's strcmp95, where the textbook formula keeps the fraction.
+    let transpositions = source
+        .zip(&source_matches)
+        .filter_map(|(unit, &matched)| matched.then_some(unit))
+        .zip(
+            target
+                .zip(&target_matches)
+                .filter_map(|(unit, &matched)| matched.then_some(unit)),
+        )
+        .filter(|(l, r)| l != r)
+        .count()
+        / 2;
+
+    let matches = matches as f64;
+    (matches / source_len as f64
+        + matches / target_len as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0
+}
+
+/// Computes the Jaro-Winkler similarity between the units of `source` and `target`.
+pub(crate) fn jaro_winkler_similarity<T: Symbol>(
+    source: &[T],
+    target: &[T],
+    prefix_scale: f64,
+    boost_threshold: f64,
+) -> f64 {
+    jaro_winkler_similarity_streamed(
+        source.iter().copied(),
+        source.len(),
+        target.iter().copied(),
+        target.len(),
+        prefix_scale,
+        boost_threshold,
+    )
+}
+
+/// Computes the Jaro-Winkler similarity between the units read from `source` and `target`,
+/// made up of `source_len` and `target_len` units respectively.
+pub(crate) fn jaro_winkler_similarity_streamed<T, S, I>(
+    source: S,
+    source_len: usize,
+    target: I,
+    target_len: usize,
+    prefix_scale: f64,
+    boost_threshold: f64,
+) -> f64
+where
+    T: Symbol,
+    S: Iterator<Item = T> + Clone,
+    I: Iterator<Item = T> + Clone,
+{
+    let similarity =
+        jaro_similarity_streamed(source.clone(), source_len, target.clone(), target_len);
+
+    if similarity <= boost_threshold {
+        return similarity;
+    }
+
+    let prefix = source
+        .zip(target)
+        .take(4)
+        .take_while(|(l, r)| l == r)
+        .count();
+
+    similarity + prefix as f64 * prefix_scale * (1.0 - similarity)
+}
+
+/// Returns the length in units of the longest common subsequence of `source` and `target`.
+pub(crate) fn lcs_length<T: Symbol>(source: &[T], target: &[T]) -> usize {
+    let (stripped_source, target) = strip_common_affixes(source, target);
+    let common = source.len() - stripped_source.len();
+    let source = stripped_source;
+
+    if source.is_empty() || target.is_empty() {
+        return common;
+    }
+
+    // micro optimization: `lengths` vector has the same cardinality
+    // of target's units so we bet on the shortest sequence.
+    let (source, target) = if source.len() < target.len() {
+        (target, source)
+    } else {
+        (source, target)
+    };
+
+    common + lcs_length_streamed(source.iter().copied(), target.iter().copied(), target.len())
+}
+
+/// Returns the length in units of the longest common subsequence of the units read from
+/// `source` and `target`, the latter being made up of `target_len` units and read once
+/// per unit of the former.
+pub(crate) fn lcs_length_streamed<T, S, I>(source: S, target: I, target_len: usize) -> usize
+where
+    T: Symbol,
+    S: Iterator<Item = T>,
+    I: Iterator<Item = T> + Clone,
+{
+    let mut lengths = vec![0; target_len + 1];
+
+    for source_unit in source {
+        let mut corner = 0;
+
+        for (target_index, target_unit) in target.clone().enumerate() {
+            let upper = lengths[target_index + 1];
+
+            lengths[target_index + 1] = if source_unit == target_unit {
+                corner + 1
+            } else {
+                usize::max(lengths[target_index], upper)
+            };
+
+            corner = upper;
+        }
+    }
+
+    lengths[target_len]
+}